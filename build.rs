@@ -5,8 +5,19 @@ pub const BPP: u32 = 4;
 pub const TILE_SIZE: u32 = 8;
 
 fn main() {
-    let bmp = include_bytes!("src/img/spritesheet.bmp");
-    let image = Bmp::<Rgb888>::from_slice(bmp).expect("Failed to parse BMP file");
+    generate_tiles("src/img/spritesheet.bmp", "sprites.bin", "SPRITES_BIN");
+    generate_tiles("src/img/keys.bmp", "keys.bin", "KEYS_BIN");
+    generate_tiles("src/img/font.bmp", "font.bin", "FONT_BIN");
+    generate_pcm("src/audio/splash.wav", "splash.pcm", "SPLASH_PCM");
+    generate_pcm("src/audio/start.wav", "start.pcm", "START_PCM");
+    generate_pcm("src/audio/whoosh.wav", "whoosh.pcm", "WHOOSH_PCM");
+    generate_sine_table("sine.rs", "SINE_TABLE");
+}
+
+fn generate_tiles(image_path: &str, bin_name: &str, env_name: &str) {
+    let bmp = std::fs::read(image_path)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {}", image_path, err));
+    let image = Bmp::<Rgb888>::from_slice(&bmp).expect("Failed to parse BMP file");
     let mut buffer = {
         let size = image.size().width * image.size().height;
         vec![0u8; size as usize]
@@ -67,7 +78,7 @@ fn main() {
 
     // Now, let's write the buffer to a file in OUT_DIR so we can import it in our binary
     let out_dir = std::env::var("OUT_DIR").unwrap();
-    let path = std::path::Path::new(&out_dir).join("sprites.bin");
+    let path = std::path::Path::new(&out_dir).join(bin_name);
 
     // Transmute the buffer to a byte slice so we can write it to a file, we've already
     // accounded for the endianness when compacting the buffer
@@ -79,9 +90,130 @@ fn main() {
         )
     };
 
-    std::fs::write(&path, buffer).expect("Failed to write sprites.bin");
+    std::fs::write(&path, buffer)
+        .unwrap_or_else(|err| panic!("Failed to write {}: {}", bin_name, err));
 
     // Write this path to an environment variable so we can access it in our binary
-    println!("cargo:rerun-if-changed=src/img/spritesheet.bmp");
-    println!("cargo:rustc-env=SPRITES_BIN={}", path.display());
+    println!("cargo:rerun-if-changed={}", image_path);
+    println!("cargo:rustc-env={}={}", env_name, path.display());
+}
+
+/// How many bytes Direct Sound's FIFO is topped up with at a time
+pub const FIFO_REFILL_SIZE: usize = 16;
+
+/// The sample rates a WAV can have, which keep the reload value of a
+/// timer with no prescaler in range
+const SAMPLE_RATES: std::ops::RangeInclusive<u32> = 1_000..=65_536;
+
+fn generate_pcm(wav_path: &str, bin_name: &str, env_name: &str) {
+    let wav = std::fs::read(wav_path)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {}", wav_path, err));
+
+    assert!(
+        wav.len() >= 12 && &wav[0..4] == b"RIFF" && &wav[8..12] == b"WAVE",
+        "{} is not a WAV file",
+        wav_path
+    );
+
+    // Walk the chunks for the format and the samples, skipping anything else
+    let mut format = None;
+    let mut data = None;
+    let mut offset = 12;
+    while offset + 8 <= wav.len() {
+        let id = &wav[offset..offset + 4];
+        let size = u32::from_le_bytes(wav[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let body = &wav[offset + 8..(offset + 8 + size).min(wav.len())];
+        match id {
+            b"fmt " => format = Some(body),
+            b"data" => data = Some(body),
+            _ => {}
+        }
+
+        // Chunks are padded to an even length
+        offset += 8 + size + (size & 1);
+    }
+
+    let format = format.unwrap_or_else(|| panic!("{} has no fmt chunk", wav_path));
+    let data = data.unwrap_or_else(|| panic!("{} has no data chunk", wav_path));
+
+    assert!(format.len() >= 16, "{} has a truncated fmt chunk", wav_path);
+
+    let audio_format = u16::from_le_bytes([format[0], format[1]]);
+    let channels = u16::from_le_bytes([format[2], format[3]]) as usize;
+    let sample_rate = u32::from_le_bytes(format[4..8].try_into().unwrap());
+    let bits_per_sample = u16::from_le_bytes([format[14], format[15]]);
+    assert!(audio_format == 1, "{} isn't uncompressed PCM", wav_path);
+    assert!(
+        SAMPLE_RATES.contains(&sample_rate),
+        "{} has a sample rate of {} Hz, outside of the {} to {} Hz the timers can play",
+        wav_path,
+        sample_rate,
+        SAMPLE_RATES.start(),
+        SAMPLE_RATES.end()
+    );
+    assert!(
+        matches!(bits_per_sample, 8 | 16) && channels > 0,
+        "{} has {} channels of {}-bit samples, rather than 8 or 16-bit ones",
+        wav_path,
+        channels,
+        bits_per_sample
+    );
+
+    /*
+        Direct Sound plays signed 8-bit samples, so 8-bit WAVs, which are unsigned,
+        are shifted down, while 16-bit WAVs just keep their high byte. Stereo files
+        are mixed down to mono.
+    */
+    let bytes_per_sample = (bits_per_sample / 8) as usize;
+    let to_i16 = |sample: &[u8]| -> i16 {
+        if bits_per_sample == 8 {
+            (sample[0] as i16 - 128) << 8
+        } else {
+            i16::from_le_bytes([sample[0], sample[1]])
+        }
+    };
+
+    let mut buffer = sample_rate.to_le_bytes().to_vec();
+    for frame in data.chunks_exact(bytes_per_sample * channels) {
+        let sum: i32 = frame
+            .chunks_exact(bytes_per_sample)
+            .map(|sample| to_i16(sample) as i32)
+            .sum();
+        let mixed = sum / channels as i32;
+        buffer.push((mixed >> 8) as i8 as u8);
+    }
+
+    // The FIFO is refilled 16 bytes at a time, so pad the end with silence
+    // to stop the last refill from reading past the end of the samples
+    let padded_len = 4 + (buffer.len() - 4).div_ceil(FIFO_REFILL_SIZE) * FIFO_REFILL_SIZE;
+    buffer.resize(padded_len, 0);
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let path = std::path::Path::new(&out_dir).join(bin_name);
+    std::fs::write(&path, buffer)
+        .unwrap_or_else(|err| panic!("Failed to write {}: {}", bin_name, err));
+
+    println!("cargo:rerun-if-changed={}", wav_path);
+    println!("cargo:rustc-env={}={}", env_name, path.display());
+}
+
+/// Writes out the sine of each of the 256 steps of a turn, in 8.8 fixed
+/// point, as an array literal that can be `include!`d.
+fn generate_sine_table(file_name: &str, env_name: &str) {
+    const STEPS: usize = 256;
+    const ONE: f64 = 256.0;
+
+    let values: Vec<String> = (0..STEPS)
+        .map(|i| {
+            let angle = i as f64 * std::f64::consts::TAU / STEPS as f64;
+            ((angle.sin() * ONE).round() as i16).to_string()
+        })
+        .collect();
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let path = std::path::Path::new(&out_dir).join(file_name);
+    std::fs::write(&path, format!("[{}]", values.join(", ")))
+        .unwrap_or_else(|err| panic!("Failed to write {}: {}", file_name, err));
+
+    println!("cargo:rustc-env={}={}", env_name, path.display());
 }
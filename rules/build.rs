@@ -0,0 +1,128 @@
+pub const MIN_WORD_LENGTH: usize = 4;
+pub const MAX_WORD_LENGTH: usize = 7;
+
+fn main() {
+    generate_dictionary();
+}
+
+fn generate_dictionary() {
+    use std::fmt::Write;
+
+    let mut source = String::new();
+    writeln!(
+        source,
+        "const DICTIONARIES: [Dictionary; {}] = [",
+        MAX_WORD_LENGTH - MIN_WORD_LENGTH + 1
+    )
+    .unwrap();
+
+    for length in MIN_WORD_LENGTH..=MAX_WORD_LENGTH {
+        let answers_path = format!("src/words/{}/answers.txt", length);
+        let guesses_path = format!("src/words/{}/guesses.txt", length);
+        let answers = read_word_list(&answers_path, length);
+        let guesses = read_word_list(&guesses_path, length);
+
+        if answers.is_empty() {
+            panic!("{} must contain at least one word", answers_path);
+        }
+
+        // Answers are always valid guesses, so there's no need to store them twice
+        let guesses: Vec<_> = guesses
+            .into_iter()
+            .filter(|word| answers.binary_search(word).is_err())
+            .collect();
+
+        writeln!(source, "    Dictionary {{").unwrap();
+        write_word_list(&mut source, "answers", length, &answers);
+        write_word_list(&mut source, "guesses", length, &guesses);
+        writeln!(source, "    }},").unwrap();
+    }
+
+    writeln!(source, "];").unwrap();
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let path = std::path::Path::new(&out_dir).join("dictionary.rs");
+    std::fs::write(&path, source).expect("Failed to write dictionary.rs");
+
+    println!("cargo:rerun-if-changed=src/words");
+    println!("cargo:rustc-env=DICTIONARY_RS={}", path.display());
+}
+
+/*
+    Word lists are plain text files with one word per line. Blank lines and lines
+    starting with '#' are ignored, case doesn't matter, and the order of the words
+    doesn't matter either. The returned list is uppercase, sorted, and deduplicated
+    so that it can be binary searched at runtime.
+*/
+fn read_word_list(path: &str, length: usize) -> Vec<String> {
+    println!("cargo:rerun-if-changed={}", path);
+
+    let contents = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {}", path, err));
+
+    let mut words = Vec::new();
+    for (line_number, line) in contents.lines().enumerate() {
+        let word = line.trim();
+        if word.is_empty() || word.starts_with('#') {
+            continue;
+        }
+
+        if !word.chars().all(|c| c.is_ascii_alphabetic()) {
+            panic!(
+                "{}:{}: `{}` must only contain the letters A-Z",
+                path,
+                line_number + 1,
+                word
+            );
+        }
+
+        if word.len() != length {
+            panic!(
+                "{}:{}: `{}` must be exactly {} letters long",
+                path,
+                line_number + 1,
+                word,
+                length
+            );
+        }
+
+        words.push(word.to_ascii_uppercase());
+    }
+
+    words.sort();
+    words.dedup();
+    words
+}
+
+/*
+    Emits a `WordList`, which groups the words by their first letter so that only
+    the remaining letters need to be packed. `starts` holds the index of the first
+    word in each group, followed by the total number of words.
+*/
+fn write_word_list(source: &mut String, name: &str, length: usize, words: &[String]) {
+    use std::fmt::Write;
+
+    if words.len() > u16::MAX as usize {
+        panic!("Word lists can't contain more than {} words", u16::MAX);
+    }
+
+    let starts: Vec<_> = (b'A'..=b'Z' + 1)
+        .map(|letter| words.partition_point(|word| word.as_bytes()[0] < letter))
+        .collect();
+
+    writeln!(source, "        {}: WordList {{", name).unwrap();
+    writeln!(source, "            length: {},", length).unwrap();
+    writeln!(source, "            starts: {:?},", starts).unwrap();
+    writeln!(source, "            words: &[").unwrap();
+    for word in words {
+        writeln!(
+            source,
+            "                PackedWord::from_u8s(b\"{}\"),",
+            &word[1..]
+        )
+        .unwrap();
+    }
+
+    writeln!(source, "            ],").unwrap();
+    writeln!(source, "        }},").unwrap();
+}
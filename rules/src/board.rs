@@ -0,0 +1,299 @@
+use crate::{
+    dictionary,
+    utils::{ArrayVec, AsciiChar, WordBuffer, MAX_WORD_LENGTH},
+};
+
+pub const MIN_GUESS_COUNT: usize = 4;
+pub const MAX_GUESS_COUNT: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Difficulty {
+    Normal,
+    /// Every revealed hint must be used in subsequent guesses
+    Hard,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum State {
+    Completed,
+    Failed,
+    InProgress,
+}
+
+/*
+   The guesses made so far, how each of them scored, and what is
+   known about every letter of the keyboard. The last guess is the
+   one being typed in, until the game is over.
+
+   Letters are typed in at the cursor, which can be moved anywhere in
+   the current guess, or just past the end of it once it's filled in.
+*/
+pub struct Board {
+    word: WordBuffer,
+    difficulty: Difficulty,
+    guess_count: usize,
+    guesses: ArrayVec<WordBuffer, MAX_GUESS_COUNT>,
+    scores: ArrayVec<[LetterMatch; MAX_WORD_LENGTH], MAX_GUESS_COUNT>,
+    letter_states: [LetterMatch; 27],
+    finished_guessing: bool,
+    cursor: usize,
+}
+
+impl Board {
+    pub fn new(word: WordBuffer, guess_count: usize, difficulty: Difficulty) -> Self {
+        Self {
+            word,
+            difficulty,
+            guess_count: guess_count.min(MAX_GUESS_COUNT),
+            guesses: {
+                let mut guesses = ArrayVec::new();
+                guesses.push(WordBuffer::empty(word.len()));
+                guesses
+            },
+            scores: ArrayVec::new(),
+            letter_states: [LetterMatch::Grey; 27],
+            finished_guessing: false,
+            cursor: 0,
+        }
+    }
+
+    pub fn word(&self) -> &WordBuffer {
+        &self.word
+    }
+
+    /// How many guesses the player gets in total
+    pub fn guess_count(&self) -> usize {
+        self.guess_count
+    }
+
+    /// How many guesses have been submitted and scored
+    pub fn guesses_used(&self) -> usize {
+        self.scores.len()
+    }
+
+    /// Every guess so far, including the one being typed in
+    pub fn guesses(&self) -> impl Iterator<Item = &WordBuffer> {
+        self.guesses.iter()
+    }
+
+    /// The row of the guess being typed in, or the last guess once the game is over
+    pub fn current_row(&self) -> usize {
+        self.guesses.len() - 1
+    }
+
+    pub fn current_guess(&self) -> &WordBuffer {
+        self.guesses.nth(self.current_row()).unwrap()
+    }
+
+    /// The score of a submitted guess, or `None` if it hasn't been submitted
+    pub fn score(&self, row: usize) -> Option<&[LetterMatch; MAX_WORD_LENGTH]> {
+        self.scores.nth(row)
+    }
+
+    /// The best match for a letter across all of the submitted guesses
+    pub fn letter_state(&self, letter: AsciiChar) -> LetterMatch {
+        self.letter_states[letter.letter_index() as usize]
+    }
+
+    pub fn state(&self) -> State {
+        let last_submitted_guess = if self.guesses.len() > 1 {
+            let last_guess_index = if self.finished_guessing {
+                self.guesses.len() - 1
+            } else {
+                self.guesses.len() - 2
+            };
+
+            self.guesses.nth(last_guess_index)
+        } else {
+            None
+        };
+
+        let word_guessed =
+            last_submitted_guess.is_some_and(|guess| guess.as_slice() == self.word.as_slice());
+
+        if word_guessed {
+            State::Completed
+        } else if self.finished_guessing {
+            State::Failed
+        } else {
+            State::InProgress
+        }
+    }
+
+    /// The position in the current guess that the next letter is typed into,
+    /// which is the length of the word once the cursor has moved past the end
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor within the current guess, stopping at either end
+    pub fn move_cursor(&mut self, delta: isize) {
+        self.cursor = self
+            .cursor
+            .saturating_add_signed(delta)
+            .min(self.word.len());
+    }
+
+    /// Types a letter at the cursor, replacing whatever was there, and moves
+    /// on to the next position. Does nothing once the cursor is past the end.
+    pub fn push_letter(&mut self, letter: AsciiChar) {
+        if self.state() != State::InProgress || self.cursor >= self.word.len() {
+            return;
+        }
+
+        let cursor = self.cursor;
+        self.guesses.last_mut().unwrap().as_mut_slice()[cursor] = letter;
+        self.cursor += 1;
+    }
+
+    /// Clears the letter at the cursor, or if there isn't one, moves back and
+    /// clears the letter before it like a backspace. At the end of the guess
+    /// this removes the last letter typed.
+    pub fn pop_letter(&mut self) -> Option<AsciiChar> {
+        if self.state() != State::InProgress {
+            return None;
+        }
+
+        let cursor = self.cursor;
+        let letters = self.guesses.last_mut().unwrap().as_mut_slice();
+        let index = match letters.get(cursor) {
+            Some(letter) if *letter != AsciiChar::NULL => cursor,
+            _ => cursor.checked_sub(1)?,
+        };
+
+        self.cursor = index;
+        let letter = core::mem::replace(&mut letters[index], AsciiChar::NULL);
+        (letter != AsciiChar::NULL).then_some(letter)
+    }
+
+    /// Scores the current guess and moves on to the next one. A rejected guess
+    /// is left as it was, so the player can fix it up and try again.
+    pub fn submit(&mut self) -> Result<(), Rejection> {
+        if self.state() != State::InProgress {
+            return Err(Rejection::GameOver);
+        }
+
+        let current_guess = *self.current_guess();
+        if !current_guess.is_full() {
+            return Err(Rejection::Incomplete);
+        }
+
+        // Check if the guess is valid or not
+        if !dictionary::is_valid_guess(&current_guess) {
+            return Err(Rejection::NotInDictionary);
+        }
+
+        if self.difficulty == Difficulty::Hard {
+            self.check_hints_used(&current_guess)?;
+        }
+
+        let score = score_guess(&self.word, &current_guess);
+
+        // Update the keyboard colors
+        for (c, m) in current_guess.as_slice().iter().zip(score.iter()) {
+            self.letter_states[c.letter_index() as usize].maybe_upgrade(*m);
+        }
+
+        self.scores.push(score);
+
+        // Start on the next guess, unless that was the last one
+        self.finished_guessing = self.guesses.len() >= self.guess_count;
+        if !self.finished_guessing {
+            self.guesses.push(WordBuffer::empty(self.word.len()));
+            self.cursor = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Hard mode: green letters must stay where they were found, and yellow
+    /// letters must appear at least as many times as they've been revealed.
+    fn check_hints_used(&self, guess: &WordBuffer) -> Result<(), Rejection> {
+        for (previous, score) in self.guesses.iter().zip(self.scores.iter()) {
+            let revealed = previous.as_slice().iter().zip(score.iter());
+
+            for (i, (c, m)) in revealed.clone().enumerate() {
+                if *m == LetterMatch::Green && guess.as_slice()[i] != *c {
+                    return Err(Rejection::MissingGreen(*c));
+                }
+            }
+
+            for (c, m) in revealed.clone() {
+                if *m != LetterMatch::Yellow {
+                    continue;
+                }
+
+                let required = revealed
+                    .clone()
+                    .filter(|(p, m)| *p == c && **m != LetterMatch::Black)
+                    .count();
+
+                let used = guess.as_slice().iter().filter(|p| *p == c).count();
+                if used < required {
+                    return Err(Rejection::MissingYellow(*c));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// How much is known about a letter. The ordering of the variants matters
+/// for `maybe_upgrade`: a letter seen as absent (`Black`) in one position can
+/// still be present elsewhere in the word, so every hit outranks it.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LetterMatch {
+    Grey = 0,
+    Black = 1,
+    Yellow = 2,
+    Green = 3,
+}
+
+impl LetterMatch {
+    pub fn maybe_upgrade(&mut self, new_state: Self) {
+        if (*self as u8) < (new_state as u8) {
+            *self = new_state;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rejection {
+    Incomplete,
+    NotInDictionary,
+    /// Hard mode: a letter found in the right spot was moved or dropped
+    MissingGreen(AsciiChar),
+    /// Hard mode: a letter known to be in the word wasn't used
+    MissingYellow(AsciiChar),
+    /// The word has already been found, or every guess has been used
+    GameOver,
+}
+
+/// Scores a guess against the word using the standard Wordle rules. Exact
+/// matches are found first, then the remaining letters of the word are handed
+/// out left-to-right as yellows, so a letter is never marked more times than
+/// it appears in the word.
+pub fn score_guess(word: &WordBuffer, guess: &WordBuffer) -> [LetterMatch; MAX_WORD_LENGTH] {
+    let mut score = [LetterMatch::Black; MAX_WORD_LENGTH];
+    let mut unmatched = [0u8; 27];
+
+    for (i, (w, g)) in word.as_slice().iter().zip(guess.as_slice()).enumerate() {
+        if w == g {
+            score[i] = LetterMatch::Green;
+        } else {
+            unmatched[w.letter_index() as usize] += 1;
+        }
+    }
+
+    for (i, g) in guess.as_slice().iter().enumerate() {
+        let remaining = &mut unmatched[g.letter_index() as usize];
+        if score[i] != LetterMatch::Green && *remaining > 0 {
+            score[i] = LetterMatch::Yellow;
+            *remaining -= 1;
+        }
+    }
+
+    score
+}
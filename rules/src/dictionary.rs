@@ -0,0 +1,83 @@
+use crate::utils::{AsciiChar, PackedWord, WordBuffer, MIN_WORD_LENGTH};
+
+pub fn is_valid_guess(word: &WordBuffer) -> bool {
+    if word.as_slice().iter().any(|&c| c == AsciiChar::NULL) {
+        return false;
+    }
+
+    let dictionary = dictionary(word.len());
+    dictionary.answers.contains(word) || dictionary.guesses.contains(word)
+}
+
+pub fn random_word(length: usize, index: usize) -> WordBuffer {
+    let answers = &dictionary(length).answers;
+    answers.nth(index % answers.len())
+}
+
+fn dictionary(length: usize) -> &'static Dictionary {
+    &DICTIONARIES[length - MIN_WORD_LENGTH]
+}
+
+/*
+   Like normal Wordle, the words we pick answers from are a
+   curated list of common words, while guesses are checked
+   against a much larger list of accepted words. There's a
+   pair of lists for every word length, which are generated
+   by `build.rs` from the word lists in `src/words`. It sorts
+   them for `WordList::contains` to binary search, and drops
+   answers from the guess lists to save space in the ROM.
+*/
+struct Dictionary {
+    answers: WordList,
+    guesses: WordList,
+}
+
+/*
+   Words are grouped by their first letter, so only the rest
+   of the letters need to be stored, which keeps even seven
+   letter words down to 4 bytes each. Within a group, the
+   packed words are sorted and can be binary searched.
+*/
+struct WordList {
+    length: usize,
+    /// Where the words starting with each letter begin in `words`,
+    /// followed by the total number of words.
+    starts: [u16; 27],
+    /// Every word in the list, without its first letter
+    words: &'static [PackedWord],
+}
+
+impl WordList {
+    fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    fn contains(&self, word: &WordBuffer) -> bool {
+        let Some((first, rest)) = word.as_slice().split_first() else {
+            return false;
+        };
+
+        let letter = first.letter_index() as usize;
+        if word.len() != self.length || letter >= 26 {
+            return false;
+        }
+
+        let group = self.starts[letter] as usize..self.starts[letter + 1] as usize;
+        let packed = PackedWord::pack(rest, rest.len());
+        self.words[group].binary_search(&packed).is_ok()
+    }
+
+    fn nth(&self, index: usize) -> WordBuffer {
+        let letter = (0..26)
+            .find(|&letter| index < self.starts[letter + 1] as usize)
+            .unwrap_or(25);
+
+        let mut buffer = WordBuffer::empty(self.length);
+        let (first, rest) = buffer.as_mut_slice().split_first_mut().unwrap();
+        *first = AsciiChar(b'A' + letter as u8);
+        self.words[index].unpack(rest);
+        buffer
+    }
+}
+
+include!(env!("DICTIONARY_RS"));
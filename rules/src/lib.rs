@@ -0,0 +1,10 @@
+/*
+   The rules of the game, kept apart from anything that touches the
+   hardware so that they build for the host as well as the GBA, and
+   can be tested on either. See `tests/` for the host test suite.
+*/
+#![no_std]
+
+pub mod board;
+pub mod dictionary;
+pub mod utils;
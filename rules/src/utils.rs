@@ -0,0 +1,175 @@
+use core::mem::MaybeUninit;
+
+pub struct ArrayVec<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    pub fn new() -> Self {
+        Self {
+            data: core::array::from_fn(|_| unsafe { MaybeUninit::uninit().assume_init() }),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn nth(&self, n: usize) -> Option<&T> {
+        if n < self.len {
+            Some(unsafe { self.data[n].assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        if self.len > 0 {
+            Some(unsafe { self.data[self.len - 1].assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.len < N {
+            self.data[self.len] = MaybeUninit::new(value);
+            self.len += 1;
+        } else {
+            panic!("array is full")
+        }
+    }
+
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T> + 'a {
+        self.data
+            .iter()
+            .take(self.len)
+            .map(|x| unsafe { x.assume_init_ref() })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AsciiChar(pub u8);
+
+impl AsciiChar {
+    pub const NULL: Self = Self(0x00);
+
+    pub const fn from_u8(byte: u8) -> Self {
+        if byte >= b'A' && byte <= b'Z' {
+            Self(byte)
+        } else if byte >= b'a' && byte <= b'z' {
+            Self(b'A' + (byte - b'a'))
+        } else if byte >= b'0' && byte <= b'9' {
+            Self(byte)
+        } else {
+            Self::NULL
+        }
+    }
+
+    pub const fn letter_index(self) -> u16 {
+        if self.0 >= b'A' && self.0 <= b'Z' {
+            return (self.0 - b'A') as u16;
+        } else {
+            26
+        }
+    }
+}
+
+pub const MIN_WORD_LENGTH: usize = 4;
+pub const MAX_WORD_LENGTH: usize = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WordBuffer {
+    letters: [AsciiChar; MAX_WORD_LENGTH],
+    len: u8,
+}
+
+impl WordBuffer {
+    pub const fn empty(len: usize) -> Self {
+        assert!(len <= MAX_WORD_LENGTH);
+        Self {
+            letters: [AsciiChar::NULL; MAX_WORD_LENGTH],
+            len: len as u8,
+        }
+    }
+
+    pub const fn from_u8s(value: &[u8]) -> Self {
+        let mut buffer = Self::empty(value.len());
+        let mut i = 0;
+        while i < value.len() {
+            buffer.letters[i] = AsciiChar::from_u8(value[i]);
+            i += 1;
+        }
+
+        buffer
+    }
+
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_slice(&self) -> &[AsciiChar] {
+        &self.letters[..self.len as usize]
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [AsciiChar] {
+        &mut self.letters[..self.len as usize]
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.as_slice().iter().all(|&c| c != AsciiChar::NULL)
+    }
+}
+
+/*
+   Up to six letters packed into a u32 with 5 bits per letter, instead
+   of the byte per letter plus a length that a `WordBuffer` takes. The
+   first letter is stored in the most significant bits, so packed
+   words of the same length sort in the same order as the words
+   themselves, and can be binary searched without unpacking them.
+*/
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PackedWord(pub u32);
+
+impl PackedWord {
+    pub const BITS_PER_LETTER: u32 = 5;
+    pub const MAX_LETTERS: usize = 6;
+    const LETTER_MASK: u32 = (1 << Self::BITS_PER_LETTER) - 1;
+
+    pub const fn from_u8s(value: &[u8]) -> Self {
+        let buffer = WordBuffer::from_u8s(value);
+        Self::pack(&buffer.letters, buffer.len as usize)
+    }
+
+    pub const fn pack(letters: &[AsciiChar], len: usize) -> Self {
+        assert!(len <= Self::MAX_LETTERS);
+
+        let mut packed = 0;
+        let mut i = 0;
+        while i < len {
+            packed = (packed << Self::BITS_PER_LETTER) | letters[i].letter_index() as u32;
+            i += 1;
+        }
+
+        Self(packed)
+    }
+
+    pub fn unpack(self, letters: &mut [AsciiChar]) {
+        for (i, c) in letters.iter_mut().rev().enumerate() {
+            let letter = (self.0 >> (i as u32 * Self::BITS_PER_LETTER)) & Self::LETTER_MASK;
+            *c = if letter < 26 {
+                AsciiChar(b'A' + letter as u8)
+            } else {
+                AsciiChar::NULL
+            };
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use wordboy_rules::{
+    board::{Board, Difficulty, LetterMatch, Rejection, State},
+    utils::{AsciiChar, WordBuffer},
+};
+
+fn board(word: &str, guess_count: usize, difficulty: Difficulty) -> Board {
+    Board::new(
+        WordBuffer::from_u8s(word.as_bytes()),
+        guess_count,
+        difficulty,
+    )
+}
+
+fn type_word(board: &mut Board, word: &str) {
+    for letter in word.bytes() {
+        board.push_letter(AsciiChar::from_u8(letter));
+    }
+}
+
+fn guess(board: &mut Board, word: &str) -> Result<(), Rejection> {
+    type_word(board, word);
+    board.submit()
+}
+
+fn letter_state(board: &Board, letter: u8) -> LetterMatch {
+    board.letter_state(AsciiChar::from_u8(letter))
+}
+
+#[test]
+fn new_board_is_in_progress() {
+    let board = board("CRANE", 6, Difficulty::Normal);
+    assert_eq!(board.state(), State::InProgress);
+    assert_eq!(board.guesses_used(), 0);
+    assert_eq!(board.current_row(), 0);
+    assert_eq!(letter_state(&board, b'C'), LetterMatch::Grey);
+}
+
+#[test]
+fn guessing_the_word_first_time_wins() {
+    let mut board = board("CRANE", 6, Difficulty::Normal);
+    assert_eq!(guess(&mut board, "CRANE"), Ok(()));
+    assert_eq!(board.state(), State::Completed);
+    assert_eq!(board.guesses_used(), 1);
+}
+
+#[test]
+fn guessing_the_word_with_the_last_guess_wins() {
+    let mut board = board("CRANE", 4, Difficulty::Normal);
+    for word in ["SLATE", "TRAIN", "GHOST"] {
+        assert_eq!(guess(&mut board, word), Ok(()));
+        assert_eq!(board.state(), State::InProgress);
+    }
+
+    assert_eq!(guess(&mut board, "CRANE"), Ok(()));
+    assert_eq!(board.state(), State::Completed);
+    assert_eq!(board.guesses_used(), 4);
+}
+
+#[test]
+fn running_out_of_guesses_loses() {
+    let mut board = board("CRANE", 4, Difficulty::Normal);
+    for word in ["SLATE", "TRAIN", "GHOST", "AUDIO"] {
+        assert_eq!(board.state(), State::InProgress);
+        assert_eq!(guess(&mut board, word), Ok(()));
+    }
+
+    assert_eq!(board.state(), State::Failed);
+    assert_eq!(board.guesses_used(), 4);
+}
+
+#[test]
+fn finished_games_reject_further_input() {
+    let mut board = board("CRANE", 6, Difficulty::Normal);
+    guess(&mut board, "CRANE").unwrap();
+
+    assert_eq!(board.pop_letter(), None);
+    assert_eq!(guess(&mut board, "SLATE"), Err(Rejection::GameOver));
+    assert_eq!(board.guesses_used(), 1);
+    assert_eq!(board.state(), State::Completed);
+}
+
+#[test]
+fn incomplete_guesses_are_rejected() {
+    let mut board = board("CRANE", 6, Difficulty::Normal);
+    assert_eq!(guess(&mut board, ""), Err(Rejection::Incomplete));
+    assert_eq!(guess(&mut board, "CRAN"), Err(Rejection::Incomplete));
+    assert_eq!(board.guesses_used(), 0);
+}
+
+#[test]
+fn unknown_words_are_rejected_and_kept_for_editing() {
+    let mut board = board("CRANE", 6, Difficulty::Normal);
+    assert_eq!(guess(&mut board, "ZZZZZ"), Err(Rejection::NotInDictionary));
+    assert_eq!(board.guesses_used(), 0);
+    assert_eq!(board.current_guess(), &WordBuffer::from_u8s(b"ZZZZZ"));
+    assert_eq!(letter_state(&board, b'Z'), LetterMatch::Grey);
+
+    for _ in 0..5 {
+        assert!(board.pop_letter().is_some());
+    }
+
+    assert_eq!(board.pop_letter(), None);
+    assert_eq!(guess(&mut board, "SLATE"), Ok(()));
+}
+
+#[test]
+fn typing_into_a_full_guess_does_nothing() {
+    let mut board = board("CRANE", 6, Difficulty::Normal);
+    type_word(&mut board, "SLATEX");
+    assert_eq!(board.current_guess(), &WordBuffer::from_u8s(b"SLATE"));
+}
+
+#[test]
+fn typing_at_the_cursor_overwrites_letters() {
+    let mut board = board("CRANE", 6, Difficulty::Normal);
+    type_word(&mut board, "SLOTE");
+    assert_eq!(board.cursor(), 5);
+
+    board.move_cursor(-3);
+    type_word(&mut board, "A");
+    assert_eq!(board.current_guess(), &WordBuffer::from_u8s(b"SLATE"));
+    assert_eq!(board.cursor(), 3);
+
+    board.move_cursor(10);
+    assert_eq!(board.cursor(), 5);
+    board.move_cursor(-10);
+    assert_eq!(board.cursor(), 0);
+}
+
+#[test]
+fn deleting_clears_the_letter_at_the_cursor_before_backspacing() {
+    let mut board = board("CRANE", 6, Difficulty::Normal);
+    type_word(&mut board, "SLATE");
+
+    board.move_cursor(-4);
+    assert_eq!(board.pop_letter(), Some(AsciiChar::from_u8(b'L')));
+    assert_eq!(board.cursor(), 1);
+    assert_eq!(board.pop_letter(), Some(AsciiChar::from_u8(b'S')));
+    assert_eq!(board.cursor(), 0);
+    assert_eq!(board.pop_letter(), None);
+
+    assert_eq!(board.submit(), Err(Rejection::Incomplete));
+    type_word(&mut board, "SL");
+    assert_eq!(board.submit(), Ok(()));
+    assert_eq!(board.cursor(), 0);
+}
+
+#[test]
+fn letter_states_keep_the_best_match() {
+    let mut board = board("CRANE", 6, Difficulty::Normal);
+    guess(&mut board, "TRAIN").unwrap();
+    assert_eq!(letter_state(&board, b'T'), LetterMatch::Black);
+    assert_eq!(letter_state(&board, b'R'), LetterMatch::Green);
+    assert_eq!(letter_state(&board, b'N'), LetterMatch::Yellow);
+    assert_eq!(letter_state(&board, b'C'), LetterMatch::Grey);
+
+    // A later miss never downgrades what's already known
+    guess(&mut board, "BRAND").unwrap();
+    assert_eq!(letter_state(&board, b'N'), LetterMatch::Green);
+    guess(&mut board, "SLATE").unwrap();
+    assert_eq!(letter_state(&board, b'A'), LetterMatch::Green);
+}
+
+#[test]
+fn hard_mode_requires_green_letters_to_stay_put() {
+    let mut board = board("CRANE", 6, Difficulty::Hard);
+    guess(&mut board, "TRAIN").unwrap();
+
+    let rejection = guess(&mut board, "SLATE");
+    assert_eq!(
+        rejection,
+        Err(Rejection::MissingGreen(AsciiChar::from_u8(b'R')))
+    );
+    assert_eq!(board.guesses_used(), 1);
+}
+
+#[test]
+fn hard_mode_requires_yellow_letters_to_be_used() {
+    let mut board = board("CRANE", 6, Difficulty::Hard);
+    guess(&mut board, "TRAIN").unwrap();
+
+    let rejection = guess(&mut board, "BRACE");
+    assert_eq!(
+        rejection,
+        Err(Rejection::MissingYellow(AsciiChar::from_u8(b'N')))
+    );
+
+    for _ in 0..5 {
+        board.pop_letter();
+    }
+
+    assert_eq!(guess(&mut board, "BRAND"), Ok(()));
+}
+
+#[test]
+fn normal_mode_ignores_hints() {
+    let mut board = board("CRANE", 6, Difficulty::Normal);
+    guess(&mut board, "TRAIN").unwrap();
+    assert_eq!(guess(&mut board, "GHOST"), Ok(()));
+}
@@ -0,0 +1,63 @@
+use wordboy_rules::{
+    board::{score_guess, LetterMatch},
+    utils::WordBuffer,
+};
+
+use LetterMatch::{Black, Green, Yellow};
+
+fn score(word: &str, guess: &str) -> Vec<LetterMatch> {
+    let word = WordBuffer::from_u8s(word.as_bytes());
+    let guess = WordBuffer::from_u8s(guess.as_bytes());
+    score_guess(&word, &guess)[..word.len()].to_vec()
+}
+
+#[test]
+fn exact_match_is_all_green() {
+    assert_eq!(score("CRANE", "CRANE"), [Green; 5]);
+}
+
+#[test]
+fn missing_letters_are_black() {
+    assert_eq!(score("CRANE", "GHOST"), [Black; 5]);
+}
+
+#[test]
+fn misplaced_letters_are_yellow() {
+    assert_eq!(
+        score("CRANE", "TRAIN"),
+        [Black, Green, Green, Black, Yellow]
+    );
+}
+
+#[test]
+fn green_takes_priority_over_an_earlier_yellow() {
+    // The E at the end of CRANE is claimed by the exact match,
+    // so neither of the leading Es can be yellow.
+    assert_eq!(
+        score("CRANE", "EERIE"),
+        [Black, Black, Yellow, Black, Green]
+    );
+}
+
+#[test]
+fn duplicate_guess_letters_are_only_yellow_once_per_occurrence() {
+    // LEVEL has two Es, one of which is matched exactly, leaving
+    // one yellow for the guess' remaining three Es.
+    assert_eq!(
+        score("LEVEL", "EERIE"),
+        [Yellow, Green, Black, Black, Black]
+    );
+}
+
+#[test]
+fn duplicate_word_letters_can_all_be_yellow() {
+    assert_eq!(
+        score("ABBEY", "KEBAB"),
+        [Black, Yellow, Green, Yellow, Yellow]
+    );
+}
+
+#[test]
+fn scores_other_word_lengths() {
+    assert_eq!(score("TREE", "TEST"), [Green, Yellow, Black, Black]);
+}
@@ -0,0 +1,12 @@
+/// Halts the CPU until the next VBlank interrupt. The VBlank interrupt has
+/// to be enabled, and acknowledged by `interrupt`'s dispatcher, or this will
+/// never return.
+#[inline]
+pub fn vblank_intr_wait() {
+    unsafe {
+        core::arch::asm! {
+            "swi #0x05",
+            clobber_abi("C"),
+        }
+    }
+}
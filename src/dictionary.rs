@@ -1,2338 +0,0 @@
-use crate::utils::{AsciiChar, WordBuffer};
-
-pub fn is_valid_guess(word: &WordBuffer) -> bool {
-    if word.as_slice().iter().any(|&c| c == AsciiChar::NULL) {
-        return false;
-    }
-
-    DICTIONARY.binary_search(word).is_ok()
-}
-
-pub fn random_word(index: usize) -> WordBuffer {
-    DICTIONARY[index % DICTIONARY.len()]
-}
-
-/*
-   Unlike normal Wordle, we're using the same dictionary for
-   words and guesses just because we only have so much
-   space in the ROM. This list must be sorted, because
-   we binary search it in `is_valid_guess`.
-*/
-const DICTIONARY: &[WordBuffer] = &[
-    WordBuffer::from_u8s([b'a', b'a', b'a', b'a', b'a']),
-    WordBuffer::from_u8s([b'a', b'b', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b'a', b'b', b'a', b's', b'e']),
-    WordBuffer::from_u8s([b'a', b'b', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b'a', b'b', b'b', b'e', b'y']),
-    WordBuffer::from_u8s([b'a', b'b', b'b', b'o', b't']),
-    WordBuffer::from_u8s([b'a', b'b', b'h', b'o', b'r']),
-    WordBuffer::from_u8s([b'a', b'b', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b'a', b'b', b'l', b'e', b'd']),
-    WordBuffer::from_u8s([b'a', b'b', b'o', b'd', b'e']),
-    WordBuffer::from_u8s([b'a', b'b', b'o', b'r', b't']),
-    WordBuffer::from_u8s([b'a', b'b', b'o', b'u', b't']),
-    WordBuffer::from_u8s([b'a', b'b', b'o', b'v', b'e']),
-    WordBuffer::from_u8s([b'a', b'b', b'u', b's', b'e']),
-    WordBuffer::from_u8s([b'a', b'b', b'y', b's', b's']),
-    WordBuffer::from_u8s([b'a', b'c', b'o', b'r', b'n']),
-    WordBuffer::from_u8s([b'a', b'c', b'r', b'i', b'd']),
-    WordBuffer::from_u8s([b'a', b'c', b't', b'o', b'r']),
-    WordBuffer::from_u8s([b'a', b'c', b'u', b't', b'e']),
-    WordBuffer::from_u8s([b'a', b'd', b'a', b'g', b'e']),
-    WordBuffer::from_u8s([b'a', b'd', b'a', b'p', b't']),
-    WordBuffer::from_u8s([b'a', b'd', b'e', b'p', b't']),
-    WordBuffer::from_u8s([b'a', b'd', b'm', b'i', b'n']),
-    WordBuffer::from_u8s([b'a', b'd', b'm', b'i', b't']),
-    WordBuffer::from_u8s([b'a', b'd', b'o', b'b', b'e']),
-    WordBuffer::from_u8s([b'a', b'd', b'o', b'p', b't']),
-    WordBuffer::from_u8s([b'a', b'd', b'o', b'r', b'e']),
-    WordBuffer::from_u8s([b'a', b'd', b'o', b'r', b'n']),
-    WordBuffer::from_u8s([b'a', b'd', b'u', b'l', b't']),
-    WordBuffer::from_u8s([b'a', b'f', b'f', b'i', b'x']),
-    WordBuffer::from_u8s([b'a', b'f', b'i', b'r', b'e']),
-    WordBuffer::from_u8s([b'a', b'f', b'o', b'o', b't']),
-    WordBuffer::from_u8s([b'a', b'f', b'o', b'u', b'l']),
-    WordBuffer::from_u8s([b'a', b'f', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'a', b'g', b'a', b'i', b'n']),
-    WordBuffer::from_u8s([b'a', b'g', b'a', b'p', b'e']),
-    WordBuffer::from_u8s([b'a', b'g', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b'a', b'g', b'e', b'n', b't']),
-    WordBuffer::from_u8s([b'a', b'g', b'i', b'l', b'e']),
-    WordBuffer::from_u8s([b'a', b'g', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'a', b'g', b'l', b'o', b'w']),
-    WordBuffer::from_u8s([b'a', b'g', b'o', b'n', b'y']),
-    WordBuffer::from_u8s([b'a', b'g', b'o', b'r', b'a']),
-    WordBuffer::from_u8s([b'a', b'g', b'r', b'e', b'e']),
-    WordBuffer::from_u8s([b'a', b'h', b'e', b'a', b'd']),
-    WordBuffer::from_u8s([b'a', b'i', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'a', b'i', b's', b'l', b'e']),
-    WordBuffer::from_u8s([b'a', b'l', b'a', b'r', b'm']),
-    WordBuffer::from_u8s([b'a', b'l', b'b', b'u', b'm']),
-    WordBuffer::from_u8s([b'a', b'l', b'e', b'r', b't']),
-    WordBuffer::from_u8s([b'a', b'l', b'g', b'a', b'e']),
-    WordBuffer::from_u8s([b'a', b'l', b'i', b'b', b'i']),
-    WordBuffer::from_u8s([b'a', b'l', b'i', b'e', b'n']),
-    WordBuffer::from_u8s([b'a', b'l', b'i', b'g', b'n']),
-    WordBuffer::from_u8s([b'a', b'l', b'i', b'k', b'e']),
-    WordBuffer::from_u8s([b'a', b'l', b'i', b'v', b'e']),
-    WordBuffer::from_u8s([b'a', b'l', b'l', b'a', b'y']),
-    WordBuffer::from_u8s([b'a', b'l', b'l', b'e', b'y']),
-    WordBuffer::from_u8s([b'a', b'l', b'l', b'o', b't']),
-    WordBuffer::from_u8s([b'a', b'l', b'l', b'o', b'w']),
-    WordBuffer::from_u8s([b'a', b'l', b'l', b'o', b'y']),
-    WordBuffer::from_u8s([b'a', b'l', b'o', b'f', b't']),
-    WordBuffer::from_u8s([b'a', b'l', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b'a', b'l', b'o', b'n', b'g']),
-    WordBuffer::from_u8s([b'a', b'l', b'o', b'o', b'f']),
-    WordBuffer::from_u8s([b'a', b'l', b'o', b'u', b'd']),
-    WordBuffer::from_u8s([b'a', b'l', b'p', b'h', b'a']),
-    WordBuffer::from_u8s([b'a', b'l', b't', b'a', b'r']),
-    WordBuffer::from_u8s([b'a', b'l', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'a', b'm', b'a', b's', b's']),
-    WordBuffer::from_u8s([b'a', b'm', b'a', b'z', b'e']),
-    WordBuffer::from_u8s([b'a', b'm', b'b', b'e', b'r']),
-    WordBuffer::from_u8s([b'a', b'm', b'b', b'l', b'e']),
-    WordBuffer::from_u8s([b'a', b'm', b'e', b'n', b'd']),
-    WordBuffer::from_u8s([b'a', b'm', b'i', b's', b's']),
-    WordBuffer::from_u8s([b'a', b'm', b'i', b't', b'y']),
-    WordBuffer::from_u8s([b'a', b'm', b'o', b'n', b'g']),
-    WordBuffer::from_u8s([b'a', b'm', b'p', b'l', b'e']),
-    WordBuffer::from_u8s([b'a', b'm', b'p', b'l', b'y']),
-    WordBuffer::from_u8s([b'a', b'm', b'u', b's', b'e']),
-    WordBuffer::from_u8s([b'a', b'n', b'g', b'e', b'l']),
-    WordBuffer::from_u8s([b'a', b'n', b'g', b'e', b'r']),
-    WordBuffer::from_u8s([b'a', b'n', b'g', b'l', b'e']),
-    WordBuffer::from_u8s([b'a', b'n', b'g', b'r', b'y']),
-    WordBuffer::from_u8s([b'a', b'n', b'g', b's', b't']),
-    WordBuffer::from_u8s([b'a', b'n', b'i', b'm', b'e']),
-    WordBuffer::from_u8s([b'a', b'n', b'k', b'l', b'e']),
-    WordBuffer::from_u8s([b'a', b'n', b'n', b'e', b'x']),
-    WordBuffer::from_u8s([b'a', b'n', b'n', b'o', b'y']),
-    WordBuffer::from_u8s([b'a', b'n', b'n', b'u', b'l']),
-    WordBuffer::from_u8s([b'a', b'n', b'o', b'd', b'e']),
-    WordBuffer::from_u8s([b'a', b'n', b't', b'i', b'c']),
-    WordBuffer::from_u8s([b'a', b'n', b'v', b'i', b'l']),
-    WordBuffer::from_u8s([b'a', b'o', b'r', b't', b'a']),
-    WordBuffer::from_u8s([b'a', b'p', b'a', b'r', b't']),
-    WordBuffer::from_u8s([b'a', b'p', b'h', b'i', b'd']),
-    WordBuffer::from_u8s([b'a', b'p', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'a', b'p', b'n', b'e', b'a']),
-    WordBuffer::from_u8s([b'a', b'p', b'p', b'l', b'e']),
-    WordBuffer::from_u8s([b'a', b'p', b'p', b'l', b'y']),
-    WordBuffer::from_u8s([b'a', b'p', b'r', b'o', b'n']),
-    WordBuffer::from_u8s([b'a', b'p', b't', b'l', b'y']),
-    WordBuffer::from_u8s([b'a', b'r', b'b', b'o', b'r']),
-    WordBuffer::from_u8s([b'a', b'r', b'd', b'o', b'r']),
-    WordBuffer::from_u8s([b'a', b'r', b'e', b'n', b'a']),
-    WordBuffer::from_u8s([b'a', b'r', b'g', b'u', b'e']),
-    WordBuffer::from_u8s([b'a', b'r', b'i', b's', b'e']),
-    WordBuffer::from_u8s([b'a', b'r', b'm', b'o', b'r']),
-    WordBuffer::from_u8s([b'a', b'r', b'o', b'm', b'a']),
-    WordBuffer::from_u8s([b'a', b'r', b'o', b's', b'e']),
-    WordBuffer::from_u8s([b'a', b'r', b'r', b'a', b'y']),
-    WordBuffer::from_u8s([b'a', b'r', b'r', b'o', b'w']),
-    WordBuffer::from_u8s([b'a', b'r', b's', b'o', b'n']),
-    WordBuffer::from_u8s([b'a', b'r', b't', b's', b'y']),
-    WordBuffer::from_u8s([b'a', b's', b'c', b'o', b't']),
-    WordBuffer::from_u8s([b'a', b's', b'h', b'e', b'n']),
-    WordBuffer::from_u8s([b'a', b's', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b'a', b's', b'k', b'e', b'w']),
-    WordBuffer::from_u8s([b'a', b's', b's', b'a', b'y']),
-    WordBuffer::from_u8s([b'a', b's', b's', b'e', b't']),
-    WordBuffer::from_u8s([b'a', b't', b'o', b'l', b'l']),
-    WordBuffer::from_u8s([b'a', b't', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b'a', b't', b't', b'i', b'c']),
-    WordBuffer::from_u8s([b'a', b'u', b'd', b'i', b'o']),
-    WordBuffer::from_u8s([b'a', b'u', b'd', b'i', b't']),
-    WordBuffer::from_u8s([b'a', b'u', b'g', b'u', b'r']),
-    WordBuffer::from_u8s([b'a', b'u', b'n', b't', b'y']),
-    WordBuffer::from_u8s([b'a', b'v', b'a', b'i', b'l']),
-    WordBuffer::from_u8s([b'a', b'v', b'e', b'r', b't']),
-    WordBuffer::from_u8s([b'a', b'v', b'i', b'a', b'n']),
-    WordBuffer::from_u8s([b'a', b'v', b'o', b'i', b'd']),
-    WordBuffer::from_u8s([b'a', b'w', b'a', b'i', b't']),
-    WordBuffer::from_u8s([b'a', b'w', b'a', b'k', b'e']),
-    WordBuffer::from_u8s([b'a', b'w', b'a', b'r', b'd']),
-    WordBuffer::from_u8s([b'a', b'w', b'a', b'r', b'e']),
-    WordBuffer::from_u8s([b'a', b'w', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b'a', b'w', b'f', b'u', b'l']),
-    WordBuffer::from_u8s([b'a', b'w', b'o', b'k', b'e']),
-    WordBuffer::from_u8s([b'a', b'x', b'i', b'a', b'l']),
-    WordBuffer::from_u8s([b'a', b'x', b'i', b'o', b'm']),
-    WordBuffer::from_u8s([b'a', b'x', b'i', b'o', b'n']),
-    WordBuffer::from_u8s([b'a', b'z', b'u', b'r', b'e']),
-    WordBuffer::from_u8s([b'b', b'a', b'c', b'o', b'n']),
-    WordBuffer::from_u8s([b'b', b'a', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'b', b'a', b'd', b'l', b'y']),
-    WordBuffer::from_u8s([b'b', b'a', b'g', b'e', b'l']),
-    WordBuffer::from_u8s([b'b', b'a', b'g', b'g', b'y']),
-    WordBuffer::from_u8s([b'b', b'a', b'k', b'e', b'r']),
-    WordBuffer::from_u8s([b'b', b'a', b'l', b'e', b'r']),
-    WordBuffer::from_u8s([b'b', b'a', b'l', b'm', b'y']),
-    WordBuffer::from_u8s([b'b', b'a', b'n', b'a', b'l']),
-    WordBuffer::from_u8s([b'b', b'a', b'n', b'j', b'o']),
-    WordBuffer::from_u8s([b'b', b'a', b'r', b'g', b'e']),
-    WordBuffer::from_u8s([b'b', b'a', b'r', b'o', b'n']),
-    WordBuffer::from_u8s([b'b', b'a', b's', b'a', b'l']),
-    WordBuffer::from_u8s([b'b', b'a', b's', b'i', b'c']),
-    WordBuffer::from_u8s([b'b', b'a', b's', b'i', b'l']),
-    WordBuffer::from_u8s([b'b', b'a', b's', b'i', b'n']),
-    WordBuffer::from_u8s([b'b', b'a', b's', b'i', b's']),
-    WordBuffer::from_u8s([b'b', b'a', b's', b't', b'e']),
-    WordBuffer::from_u8s([b'b', b'a', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'b', b'a', b't', b'h', b'e']),
-    WordBuffer::from_u8s([b'b', b'a', b't', b'o', b'n']),
-    WordBuffer::from_u8s([b'b', b'a', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'b', b'a', b'w', b'd', b'y']),
-    WordBuffer::from_u8s([b'b', b'a', b'y', b'o', b'u']),
-    WordBuffer::from_u8s([b'b', b'e', b'a', b'c', b'h']),
-    WordBuffer::from_u8s([b'b', b'e', b'a', b'd', b'y']),
-    WordBuffer::from_u8s([b'b', b'e', b'a', b'r', b'd']),
-    WordBuffer::from_u8s([b'b', b'e', b'a', b's', b't']),
-    WordBuffer::from_u8s([b'b', b'e', b'e', b'c', b'h']),
-    WordBuffer::from_u8s([b'b', b'e', b'e', b'f', b'y']),
-    WordBuffer::from_u8s([b'b', b'e', b'f', b'i', b't']),
-    WordBuffer::from_u8s([b'b', b'e', b'g', b'a', b'n']),
-    WordBuffer::from_u8s([b'b', b'e', b'g', b'a', b't']),
-    WordBuffer::from_u8s([b'b', b'e', b'g', b'e', b't']),
-    WordBuffer::from_u8s([b'b', b'e', b'g', b'i', b'n']),
-    WordBuffer::from_u8s([b'b', b'e', b'g', b'u', b'n']),
-    WordBuffer::from_u8s([b'b', b'e', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'b', b'e', b'l', b'c', b'h']),
-    WordBuffer::from_u8s([b'b', b'e', b'l', b'i', b'e']),
-    WordBuffer::from_u8s([b'b', b'e', b'l', b'l', b'e']),
-    WordBuffer::from_u8s([b'b', b'e', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'b', b'e', b'l', b'o', b'w']),
-    WordBuffer::from_u8s([b'b', b'e', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'b', b'e', b'r', b'e', b't']),
-    WordBuffer::from_u8s([b'b', b'e', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'b', b'e', b'r', b't', b'h']),
-    WordBuffer::from_u8s([b'b', b'e', b's', b'e', b't']),
-    WordBuffer::from_u8s([b'b', b'e', b't', b'e', b'l']),
-    WordBuffer::from_u8s([b'b', b'e', b'v', b'e', b'l']),
-    WordBuffer::from_u8s([b'b', b'e', b'z', b'e', b'l']),
-    WordBuffer::from_u8s([b'b', b'i', b'b', b'l', b'e']),
-    WordBuffer::from_u8s([b'b', b'i', b'c', b'e', b'p']),
-    WordBuffer::from_u8s([b'b', b'i', b'd', b'd', b'y']),
-    WordBuffer::from_u8s([b'b', b'i', b'g', b'o', b't']),
-    WordBuffer::from_u8s([b'b', b'i', b'l', b'g', b'e']),
-    WordBuffer::from_u8s([b'b', b'i', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'b', b'i', b'n', b'g', b'e']),
-    WordBuffer::from_u8s([b'b', b'i', b'n', b'g', b'o']),
-    WordBuffer::from_u8s([b'b', b'i', b'o', b'm', b'e']),
-    WordBuffer::from_u8s([b'b', b'i', b'r', b'c', b'h']),
-    WordBuffer::from_u8s([b'b', b'i', b'r', b't', b'h']),
-    WordBuffer::from_u8s([b'b', b'i', b's', b'o', b'n']),
-    WordBuffer::from_u8s([b'b', b'i', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'b', b'l', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b'b', b'l', b'a', b'd', b'e']),
-    WordBuffer::from_u8s([b'b', b'l', b'a', b'm', b'e']),
-    WordBuffer::from_u8s([b'b', b'l', b'a', b'n', b'd']),
-    WordBuffer::from_u8s([b'b', b'l', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b'b', b'l', b'a', b'r', b'e']),
-    WordBuffer::from_u8s([b'b', b'l', b'a', b's', b't']),
-    WordBuffer::from_u8s([b'b', b'l', b'a', b'z', b'e']),
-    WordBuffer::from_u8s([b'b', b'l', b'e', b'a', b'k']),
-    WordBuffer::from_u8s([b'b', b'l', b'e', b'a', b't']),
-    WordBuffer::from_u8s([b'b', b'l', b'e', b'e', b'd']),
-    WordBuffer::from_u8s([b'b', b'l', b'e', b'e', b'p']),
-    WordBuffer::from_u8s([b'b', b'l', b'e', b'n', b'd']),
-    WordBuffer::from_u8s([b'b', b'l', b'e', b's', b's']),
-    WordBuffer::from_u8s([b'b', b'l', b'i', b'm', b'p']),
-    WordBuffer::from_u8s([b'b', b'l', b'i', b'n', b'd']),
-    WordBuffer::from_u8s([b'b', b'l', b'i', b'n', b'k']),
-    WordBuffer::from_u8s([b'b', b'l', b'i', b's', b's']),
-    WordBuffer::from_u8s([b'b', b'l', b'i', b't', b'z']),
-    WordBuffer::from_u8s([b'b', b'l', b'o', b'a', b't']),
-    WordBuffer::from_u8s([b'b', b'l', b'o', b'c', b'k']),
-    WordBuffer::from_u8s([b'b', b'l', b'o', b'k', b'e']),
-    WordBuffer::from_u8s([b'b', b'l', b'o', b'n', b'd']),
-    WordBuffer::from_u8s([b'b', b'l', b'o', b'o', b'd']),
-    WordBuffer::from_u8s([b'b', b'l', b'o', b'o', b'm']),
-    WordBuffer::from_u8s([b'b', b'l', b'o', b'w', b'n']),
-    WordBuffer::from_u8s([b'b', b'l', b'u', b'e', b'r']),
-    WordBuffer::from_u8s([b'b', b'l', b'u', b'f', b'f']),
-    WordBuffer::from_u8s([b'b', b'l', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b'b', b'l', b'u', b'r', b'b']),
-    WordBuffer::from_u8s([b'b', b'l', b'u', b'r', b't']),
-    WordBuffer::from_u8s([b'b', b'l', b'u', b's', b'h']),
-    WordBuffer::from_u8s([b'b', b'o', b'a', b'r', b'd']),
-    WordBuffer::from_u8s([b'b', b'o', b'a', b's', b't']),
-    WordBuffer::from_u8s([b'b', b'o', b'b', b'b', b'y']),
-    WordBuffer::from_u8s([b'b', b'o', b'n', b'e', b'y']),
-    WordBuffer::from_u8s([b'b', b'o', b'n', b'g', b'o']),
-    WordBuffer::from_u8s([b'b', b'o', b'n', b'u', b's']),
-    WordBuffer::from_u8s([b'b', b'o', b'o', b'b', b'y']),
-    WordBuffer::from_u8s([b'b', b'o', b'o', b's', b't']),
-    WordBuffer::from_u8s([b'b', b'o', b'o', b't', b'h']),
-    WordBuffer::from_u8s([b'b', b'o', b'o', b't', b'y']),
-    WordBuffer::from_u8s([b'b', b'o', b'o', b'z', b'e']),
-    WordBuffer::from_u8s([b'b', b'o', b'o', b'z', b'y']),
-    WordBuffer::from_u8s([b'b', b'o', b'r', b'a', b'x']),
-    WordBuffer::from_u8s([b'b', b'o', b'r', b'n', b'e']),
-    WordBuffer::from_u8s([b'b', b'o', b's', b'o', b'm']),
-    WordBuffer::from_u8s([b'b', b'o', b's', b's', b'y']),
-    WordBuffer::from_u8s([b'b', b'o', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'b', b'o', b'u', b'g', b'h']),
-    WordBuffer::from_u8s([b'b', b'o', b'u', b'l', b'e']),
-    WordBuffer::from_u8s([b'b', b'o', b'u', b'n', b'd']),
-    WordBuffer::from_u8s([b'b', b'o', b'w', b'e', b'l']),
-    WordBuffer::from_u8s([b'b', b'o', b'x', b'e', b'r']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b'c', b'e']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b'i', b'd']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b'i', b'n']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b'k', b'e']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b'n', b'd']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b's', b's']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b'v', b'o']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b'w', b'l']),
-    WordBuffer::from_u8s([b'b', b'r', b'a', b'w', b'n']),
-    WordBuffer::from_u8s([b'b', b'r', b'e', b'a', b'd']),
-    WordBuffer::from_u8s([b'b', b'r', b'e', b'a', b'k']),
-    WordBuffer::from_u8s([b'b', b'r', b'e', b'e', b'd']),
-    WordBuffer::from_u8s([b'b', b'r', b'i', b'a', b'r']),
-    WordBuffer::from_u8s([b'b', b'r', b'i', b'b', b'e']),
-    WordBuffer::from_u8s([b'b', b'r', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b'b', b'r', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b'b', b'r', b'i', b'e', b'f']),
-    WordBuffer::from_u8s([b'b', b'r', b'i', b'n', b'e']),
-    WordBuffer::from_u8s([b'b', b'r', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'b', b'r', b'i', b'n', b'k']),
-    WordBuffer::from_u8s([b'b', b'r', b'i', b'n', b'y']),
-    WordBuffer::from_u8s([b'b', b'r', b'i', b's', b'k']),
-    WordBuffer::from_u8s([b'b', b'r', b'o', b'a', b'd']),
-    WordBuffer::from_u8s([b'b', b'r', b'o', b'i', b'l']),
-    WordBuffer::from_u8s([b'b', b'r', b'o', b'k', b'e']),
-    WordBuffer::from_u8s([b'b', b'r', b'o', b'o', b'd']),
-    WordBuffer::from_u8s([b'b', b'r', b'o', b'o', b'k']),
-    WordBuffer::from_u8s([b'b', b'r', b'o', b'o', b'm']),
-    WordBuffer::from_u8s([b'b', b'r', b'o', b't', b'h']),
-    WordBuffer::from_u8s([b'b', b'r', b'o', b'w', b'n']),
-    WordBuffer::from_u8s([b'b', b'r', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b'b', b'r', b'u', b's', b'h']),
-    WordBuffer::from_u8s([b'b', b'r', b'u', b't', b'e']),
-    WordBuffer::from_u8s([b'b', b'u', b'd', b'd', b'y']),
-    WordBuffer::from_u8s([b'b', b'u', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'b', b'u', b'g', b'g', b'y']),
-    WordBuffer::from_u8s([b'b', b'u', b'g', b'l', b'e']),
-    WordBuffer::from_u8s([b'b', b'u', b'i', b'l', b'd']),
-    WordBuffer::from_u8s([b'b', b'u', b'i', b'l', b't']),
-    WordBuffer::from_u8s([b'b', b'u', b'l', b'g', b'e']),
-    WordBuffer::from_u8s([b'b', b'u', b'l', b'k', b'y']),
-    WordBuffer::from_u8s([b'b', b'u', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'b', b'u', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'b', b'u', b'n', b'n', b'y']),
-    WordBuffer::from_u8s([b'b', b'u', b'r', b'l', b'y']),
-    WordBuffer::from_u8s([b'b', b'u', b'r', b'n', b't']),
-    WordBuffer::from_u8s([b'b', b'u', b'r', b's', b't']),
-    WordBuffer::from_u8s([b'b', b'u', b's', b'e', b'd']),
-    WordBuffer::from_u8s([b'b', b'u', b's', b'h', b'y']),
-    WordBuffer::from_u8s([b'b', b'u', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'b', b'u', b't', b't', b'e']),
-    WordBuffer::from_u8s([b'b', b'u', b'x', b'o', b'm']),
-    WordBuffer::from_u8s([b'b', b'u', b'y', b'e', b'r']),
-    WordBuffer::from_u8s([b'b', b'y', b'l', b'a', b'w']),
-    WordBuffer::from_u8s([b'c', b'a', b'b', b'a', b'l']),
-    WordBuffer::from_u8s([b'c', b'a', b'b', b'b', b'y']),
-    WordBuffer::from_u8s([b'c', b'a', b'b', b'i', b'n']),
-    WordBuffer::from_u8s([b'c', b'a', b'b', b'l', b'e']),
-    WordBuffer::from_u8s([b'c', b'a', b'c', b'a', b'o']),
-    WordBuffer::from_u8s([b'c', b'a', b'c', b'h', b'e']),
-    WordBuffer::from_u8s([b'c', b'a', b'c', b't', b'i']),
-    WordBuffer::from_u8s([b'c', b'a', b'd', b'd', b'y']),
-    WordBuffer::from_u8s([b'c', b'a', b'd', b'e', b't']),
-    WordBuffer::from_u8s([b'c', b'a', b'g', b'e', b'y']),
-    WordBuffer::from_u8s([b'c', b'a', b'i', b'r', b'n']),
-    WordBuffer::from_u8s([b'c', b'a', b'm', b'e', b'l']),
-    WordBuffer::from_u8s([b'c', b'a', b'm', b'e', b'o']),
-    WordBuffer::from_u8s([b'c', b'a', b'n', b'a', b'l']),
-    WordBuffer::from_u8s([b'c', b'a', b'n', b'd', b'y']),
-    WordBuffer::from_u8s([b'c', b'a', b'n', b'n', b'y']),
-    WordBuffer::from_u8s([b'c', b'a', b'n', b'o', b'e']),
-    WordBuffer::from_u8s([b'c', b'a', b'n', b'o', b'n']),
-    WordBuffer::from_u8s([b'c', b'a', b'p', b'e', b'r']),
-    WordBuffer::from_u8s([b'c', b'a', b'p', b'u', b't']),
-    WordBuffer::from_u8s([b'c', b'a', b'r', b'a', b't']),
-    WordBuffer::from_u8s([b'c', b'a', b'r', b'g', b'o']),
-    WordBuffer::from_u8s([b'c', b'a', b'r', b'o', b'l']),
-    WordBuffer::from_u8s([b'c', b'a', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'c', b'a', b'r', b'v', b'e']),
-    WordBuffer::from_u8s([b'c', b'a', b's', b't', b'e']),
-    WordBuffer::from_u8s([b'c', b'a', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'c', b'a', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'c', b'a', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'c', b'a', b'u', b'l', b'k']),
-    WordBuffer::from_u8s([b'c', b'a', b'u', b's', b'e']),
-    WordBuffer::from_u8s([b'c', b'a', b'v', b'i', b'l']),
-    WordBuffer::from_u8s([b'c', b'e', b'a', b's', b'e']),
-    WordBuffer::from_u8s([b'c', b'e', b'd', b'a', b'r']),
-    WordBuffer::from_u8s([b'c', b'e', b'l', b'l', b'o']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'f', b'e']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'f', b'f']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'i', b'n']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'i', b'r']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'l', b'k']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'm', b'p']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'n', b't']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'o', b's']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'r', b'd']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'r', b'm']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b'r', b't']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b's', b'e']),
-    WordBuffer::from_u8s([b'c', b'h', b'a', b's', b'm']),
-    WordBuffer::from_u8s([b'c', b'h', b'e', b'a', b'p']),
-    WordBuffer::from_u8s([b'c', b'h', b'e', b'a', b't']),
-    WordBuffer::from_u8s([b'c', b'h', b'e', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'h', b'e', b'e', b'k']),
-    WordBuffer::from_u8s([b'c', b'h', b'e', b'e', b'r']),
-    WordBuffer::from_u8s([b'c', b'h', b'e', b's', b's']),
-    WordBuffer::from_u8s([b'c', b'h', b'e', b's', b't']),
-    WordBuffer::from_u8s([b'c', b'h', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'h', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b'c', b'h', b'i', b'e', b'f']),
-    WordBuffer::from_u8s([b'c', b'h', b'i', b'l', b'd']),
-    WordBuffer::from_u8s([b'c', b'h', b'i', b'l', b'i']),
-    WordBuffer::from_u8s([b'c', b'h', b'i', b'l', b'l']),
-    WordBuffer::from_u8s([b'c', b'h', b'i', b'm', b'e']),
-    WordBuffer::from_u8s([b'c', b'h', b'i', b'n', b'a']),
-    WordBuffer::from_u8s([b'c', b'h', b'i', b'r', b'p']),
-    WordBuffer::from_u8s([b'c', b'h', b'o', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'h', b'o', b'i', b'r']),
-    WordBuffer::from_u8s([b'c', b'h', b'o', b'k', b'e']),
-    WordBuffer::from_u8s([b'c', b'h', b'o', b'r', b'd']),
-    WordBuffer::from_u8s([b'c', b'h', b'o', b'r', b'e']),
-    WordBuffer::from_u8s([b'c', b'h', b'o', b's', b'e']),
-    WordBuffer::from_u8s([b'c', b'h', b'u', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'h', b'u', b'm', b'p']),
-    WordBuffer::from_u8s([b'c', b'h', b'u', b'n', b'k']),
-    WordBuffer::from_u8s([b'c', b'h', b'u', b'r', b'n']),
-    WordBuffer::from_u8s([b'c', b'h', b'u', b't', b'e']),
-    WordBuffer::from_u8s([b'c', b'i', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'c', b'i', b'g', b'a', b'r']),
-    WordBuffer::from_u8s([b'c', b'i', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'c', b'i', b'r', b'c', b'a']),
-    WordBuffer::from_u8s([b'c', b'i', b'v', b'i', b'c']),
-    WordBuffer::from_u8s([b'c', b'i', b'v', b'i', b'l']),
-    WordBuffer::from_u8s([b'c', b'l', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'l', b'a', b'i', b'm']),
-    WordBuffer::from_u8s([b'c', b'l', b'a', b'm', b'p']),
-    WordBuffer::from_u8s([b'c', b'l', b'a', b'n', b'g']),
-    WordBuffer::from_u8s([b'c', b'l', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b'c', b'l', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b'c', b'l', b'a', b's', b'p']),
-    WordBuffer::from_u8s([b'c', b'l', b'a', b's', b's']),
-    WordBuffer::from_u8s([b'c', b'l', b'e', b'a', b'n']),
-    WordBuffer::from_u8s([b'c', b'l', b'e', b'a', b'r']),
-    WordBuffer::from_u8s([b'c', b'l', b'e', b'a', b't']),
-    WordBuffer::from_u8s([b'c', b'l', b'e', b'f', b't']),
-    WordBuffer::from_u8s([b'c', b'l', b'e', b'r', b'k']),
-    WordBuffer::from_u8s([b'c', b'l', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'l', b'i', b'f', b'f']),
-    WordBuffer::from_u8s([b'c', b'l', b'i', b'm', b'b']),
-    WordBuffer::from_u8s([b'c', b'l', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'c', b'l', b'i', b'n', b'k']),
-    WordBuffer::from_u8s([b'c', b'l', b'o', b'a', b'k']),
-    WordBuffer::from_u8s([b'c', b'l', b'o', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'l', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b'c', b'l', b'o', b's', b'e']),
-    WordBuffer::from_u8s([b'c', b'l', b'o', b't', b'h']),
-    WordBuffer::from_u8s([b'c', b'l', b'o', b'u', b'd']),
-    WordBuffer::from_u8s([b'c', b'l', b'o', b'u', b't']),
-    WordBuffer::from_u8s([b'c', b'l', b'o', b'v', b'e']),
-    WordBuffer::from_u8s([b'c', b'l', b'o', b'w', b'n']),
-    WordBuffer::from_u8s([b'c', b'l', b'u', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'l', b'u', b'e', b'd']),
-    WordBuffer::from_u8s([b'c', b'l', b'u', b'm', b'p']),
-    WordBuffer::from_u8s([b'c', b'l', b'u', b'n', b'g']),
-    WordBuffer::from_u8s([b'c', b'o', b'a', b'c', b'h']),
-    WordBuffer::from_u8s([b'c', b'o', b'a', b's', b't']),
-    WordBuffer::from_u8s([b'c', b'o', b'b', b'r', b'a']),
-    WordBuffer::from_u8s([b'c', b'o', b'c', b'o', b'a']),
-    WordBuffer::from_u8s([b'c', b'o', b'l', b'o', b'n']),
-    WordBuffer::from_u8s([b'c', b'o', b'l', b'o', b'r']),
-    WordBuffer::from_u8s([b'c', b'o', b'm', b'e', b't']),
-    WordBuffer::from_u8s([b'c', b'o', b'm', b'f', b'y']),
-    WordBuffer::from_u8s([b'c', b'o', b'm', b'i', b'c']),
-    WordBuffer::from_u8s([b'c', b'o', b'm', b'm', b'a']),
-    WordBuffer::from_u8s([b'c', b'o', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'c', b'o', b'n', b'd', b'o']),
-    WordBuffer::from_u8s([b'c', b'o', b'n', b'i', b'c']),
-    WordBuffer::from_u8s([b'c', b'o', b'p', b's', b'e']),
-    WordBuffer::from_u8s([b'c', b'o', b'r', b'a', b'l']),
-    WordBuffer::from_u8s([b'c', b'o', b'r', b'e', b'r']),
-    WordBuffer::from_u8s([b'c', b'o', b'r', b'n', b'y']),
-    WordBuffer::from_u8s([b'c', b'o', b'u', b'c', b'h']),
-    WordBuffer::from_u8s([b'c', b'o', b'u', b'g', b'h']),
-    WordBuffer::from_u8s([b'c', b'o', b'u', b'l', b'd']),
-    WordBuffer::from_u8s([b'c', b'o', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b'c', b'o', b'u', b'p', b'e']),
-    WordBuffer::from_u8s([b'c', b'o', b'u', b'r', b't']),
-    WordBuffer::from_u8s([b'c', b'o', b'v', b'e', b'n']),
-    WordBuffer::from_u8s([b'c', b'o', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'c', b'o', b'v', b'e', b't']),
-    WordBuffer::from_u8s([b'c', b'o', b'v', b'e', b'y']),
-    WordBuffer::from_u8s([b'c', b'o', b'w', b'e', b'r']),
-    WordBuffer::from_u8s([b'c', b'o', b'y', b'l', b'y']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b'f', b't']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b'm', b'p']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b'n', b'e']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b's', b's']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b'w', b'l']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b'z', b'e']),
-    WordBuffer::from_u8s([b'c', b'r', b'a', b'z', b'y']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b'a', b'k']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b'a', b'm']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b'd', b'o']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b'e', b'd']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b'e', b'k']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b'e', b'p']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b'm', b'e']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b'p', b'e']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b'p', b't']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b's', b's']),
-    WordBuffer::from_u8s([b'c', b'r', b'e', b's', b't']),
-    WordBuffer::from_u8s([b'c', b'r', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'r', b'i', b'e', b'd']),
-    WordBuffer::from_u8s([b'c', b'r', b'i', b'e', b'r']),
-    WordBuffer::from_u8s([b'c', b'r', b'i', b'm', b'e']),
-    WordBuffer::from_u8s([b'c', b'r', b'i', b'm', b'p']),
-    WordBuffer::from_u8s([b'c', b'r', b'i', b's', b'p']),
-    WordBuffer::from_u8s([b'c', b'r', b'o', b'a', b'k']),
-    WordBuffer::from_u8s([b'c', b'r', b'o', b'c', b'k']),
-    WordBuffer::from_u8s([b'c', b'r', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b'c', b'r', b'o', b'n', b'y']),
-    WordBuffer::from_u8s([b'c', b'r', b'o', b'o', b'k']),
-    WordBuffer::from_u8s([b'c', b'r', b'o', b's', b's']),
-    WordBuffer::from_u8s([b'c', b'r', b'o', b'u', b'p']),
-    WordBuffer::from_u8s([b'c', b'r', b'o', b'w', b'd']),
-    WordBuffer::from_u8s([b'c', b'r', b'o', b'w', b'n']),
-    WordBuffer::from_u8s([b'c', b'r', b'u', b'd', b'e']),
-    WordBuffer::from_u8s([b'c', b'r', b'u', b'e', b'l']),
-    WordBuffer::from_u8s([b'c', b'r', b'u', b'm', b'b']),
-    WordBuffer::from_u8s([b'c', b'r', b'u', b'm', b'p']),
-    WordBuffer::from_u8s([b'c', b'r', b'u', b's', b'h']),
-    WordBuffer::from_u8s([b'c', b'r', b'u', b's', b't']),
-    WordBuffer::from_u8s([b'c', b'r', b'y', b'p', b't']),
-    WordBuffer::from_u8s([b'c', b'u', b'b', b'i', b'c']),
-    WordBuffer::from_u8s([b'c', b'u', b'm', b'i', b'n']),
-    WordBuffer::from_u8s([b'c', b'u', b'r', b'i', b'o']),
-    WordBuffer::from_u8s([b'c', b'u', b'r', b'l', b'y']),
-    WordBuffer::from_u8s([b'c', b'u', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'c', b'u', b'r', b's', b'e']),
-    WordBuffer::from_u8s([b'c', b'u', b'r', b'v', b'e']),
-    WordBuffer::from_u8s([b'c', b'u', b'r', b'v', b'y']),
-    WordBuffer::from_u8s([b'c', b'u', b't', b'i', b'e']),
-    WordBuffer::from_u8s([b'c', b'y', b'b', b'e', b'r']),
-    WordBuffer::from_u8s([b'c', b'y', b'c', b'l', b'e']),
-    WordBuffer::from_u8s([b'c', b'y', b'n', b'i', b'c']),
-    WordBuffer::from_u8s([b'd', b'a', b'd', b'd', b'y']),
-    WordBuffer::from_u8s([b'd', b'a', b'i', b'l', b'y']),
-    WordBuffer::from_u8s([b'd', b'a', b'i', b'r', b'y']),
-    WordBuffer::from_u8s([b'd', b'a', b'i', b's', b'y']),
-    WordBuffer::from_u8s([b'd', b'a', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'd', b'a', b'n', b'c', b'e']),
-    WordBuffer::from_u8s([b'd', b'a', b'n', b'd', b'y']),
-    WordBuffer::from_u8s([b'd', b'a', b't', b'u', b'm']),
-    WordBuffer::from_u8s([b'd', b'a', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b'd', b'e', b'a', b'l', b't']),
-    WordBuffer::from_u8s([b'd', b'e', b'a', b't', b'h']),
-    WordBuffer::from_u8s([b'd', b'e', b'b', b'a', b'r']),
-    WordBuffer::from_u8s([b'd', b'e', b'b', b'i', b't']),
-    WordBuffer::from_u8s([b'd', b'e', b'b', b'u', b'g']),
-    WordBuffer::from_u8s([b'd', b'e', b'b', b'u', b't']),
-    WordBuffer::from_u8s([b'd', b'e', b'c', b'a', b'l']),
-    WordBuffer::from_u8s([b'd', b'e', b'c', b'a', b'y']),
-    WordBuffer::from_u8s([b'd', b'e', b'c', b'o', b'r']),
-    WordBuffer::from_u8s([b'd', b'e', b'c', b'o', b'y']),
-    WordBuffer::from_u8s([b'd', b'e', b'c', b'r', b'y']),
-    WordBuffer::from_u8s([b'd', b'e', b'f', b'e', b'r']),
-    WordBuffer::from_u8s([b'd', b'e', b'i', b'g', b'n']),
-    WordBuffer::from_u8s([b'd', b'e', b'i', b't', b'y']),
-    WordBuffer::from_u8s([b'd', b'e', b'l', b'a', b'y']),
-    WordBuffer::from_u8s([b'd', b'e', b'l', b't', b'a']),
-    WordBuffer::from_u8s([b'd', b'e', b'l', b'v', b'e']),
-    WordBuffer::from_u8s([b'd', b'e', b'm', b'o', b'n']),
-    WordBuffer::from_u8s([b'd', b'e', b'm', b'u', b'r']),
-    WordBuffer::from_u8s([b'd', b'e', b'n', b'i', b'm']),
-    WordBuffer::from_u8s([b'd', b'e', b'n', b's', b'e']),
-    WordBuffer::from_u8s([b'd', b'e', b'p', b'o', b't']),
-    WordBuffer::from_u8s([b'd', b'e', b'p', b't', b'h']),
-    WordBuffer::from_u8s([b'd', b'e', b'r', b'b', b'y']),
-    WordBuffer::from_u8s([b'd', b'e', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'd', b'e', b't', b'o', b'x']),
-    WordBuffer::from_u8s([b'd', b'e', b'u', b'c', b'e']),
-    WordBuffer::from_u8s([b'd', b'e', b'v', b'i', b'l']),
-    WordBuffer::from_u8s([b'd', b'i', b'a', b'r', b'y']),
-    WordBuffer::from_u8s([b'd', b'i', b'c', b'e', b'y']),
-    WordBuffer::from_u8s([b'd', b'i', b'g', b'i', b't']),
-    WordBuffer::from_u8s([b'd', b'i', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'd', b'i', b'm', b'l', b'y']),
-    WordBuffer::from_u8s([b'd', b'i', b'n', b'e', b'r']),
-    WordBuffer::from_u8s([b'd', b'i', b'n', b'g', b'o']),
-    WordBuffer::from_u8s([b'd', b'i', b'n', b'g', b'y']),
-    WordBuffer::from_u8s([b'd', b'i', b'o', b'd', b'e']),
-    WordBuffer::from_u8s([b'd', b'i', b'r', b'g', b'e']),
-    WordBuffer::from_u8s([b'd', b'i', b'r', b't', b'y']),
-    WordBuffer::from_u8s([b'd', b'i', b's', b'c', b'o']),
-    WordBuffer::from_u8s([b'd', b'i', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'd', b'i', b't', b't', b'o']),
-    WordBuffer::from_u8s([b'd', b'i', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'd', b'i', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'd', b'i', b'z', b'z', b'y']),
-    WordBuffer::from_u8s([b'd', b'o', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'd', b'o', b'd', b'g', b'y']),
-    WordBuffer::from_u8s([b'd', b'o', b'g', b'm', b'a']),
-    WordBuffer::from_u8s([b'd', b'o', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'd', b'o', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'd', b'o', b'n', b'o', b'r']),
-    WordBuffer::from_u8s([b'd', b'o', b'n', b'u', b't']),
-    WordBuffer::from_u8s([b'd', b'o', b'p', b'e', b'y']),
-    WordBuffer::from_u8s([b'd', b'o', b'u', b'b', b't']),
-    WordBuffer::from_u8s([b'd', b'o', b'u', b'g', b'h']),
-    WordBuffer::from_u8s([b'd', b'o', b'w', b'd', b'y']),
-    WordBuffer::from_u8s([b'd', b'o', b'w', b'e', b'l']),
-    WordBuffer::from_u8s([b'd', b'o', b'w', b'n', b'y']),
-    WordBuffer::from_u8s([b'd', b'o', b'w', b'r', b'y']),
-    WordBuffer::from_u8s([b'd', b'o', b'z', b'e', b'n']),
-    WordBuffer::from_u8s([b'd', b'r', b'a', b'f', b't']),
-    WordBuffer::from_u8s([b'd', b'r', b'a', b'i', b'n']),
-    WordBuffer::from_u8s([b'd', b'r', b'a', b'k', b'e']),
-    WordBuffer::from_u8s([b'd', b'r', b'a', b'm', b'a']),
-    WordBuffer::from_u8s([b'd', b'r', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b'd', b'r', b'a', b'p', b'e']),
-    WordBuffer::from_u8s([b'd', b'r', b'a', b'w', b'l']),
-    WordBuffer::from_u8s([b'd', b'r', b'a', b'w', b'n']),
-    WordBuffer::from_u8s([b'd', b'r', b'e', b'a', b'd']),
-    WordBuffer::from_u8s([b'd', b'r', b'e', b'a', b'm']),
-    WordBuffer::from_u8s([b'd', b'r', b'e', b's', b's']),
-    WordBuffer::from_u8s([b'd', b'r', b'i', b'e', b'd']),
-    WordBuffer::from_u8s([b'd', b'r', b'i', b'e', b'r']),
-    WordBuffer::from_u8s([b'd', b'r', b'i', b'f', b't']),
-    WordBuffer::from_u8s([b'd', b'r', b'i', b'l', b'l']),
-    WordBuffer::from_u8s([b'd', b'r', b'i', b'n', b'k']),
-    WordBuffer::from_u8s([b'd', b'r', b'i', b'v', b'e']),
-    WordBuffer::from_u8s([b'd', b'r', b'o', b'i', b't']),
-    WordBuffer::from_u8s([b'd', b'r', b'o', b'l', b'l']),
-    WordBuffer::from_u8s([b'd', b'r', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b'd', b'r', b'o', b'o', b'l']),
-    WordBuffer::from_u8s([b'd', b'r', b'o', b'o', b'p']),
-    WordBuffer::from_u8s([b'd', b'r', b'o', b's', b's']),
-    WordBuffer::from_u8s([b'd', b'r', b'o', b'v', b'e']),
-    WordBuffer::from_u8s([b'd', b'r', b'o', b'w', b'n']),
-    WordBuffer::from_u8s([b'd', b'r', b'u', b'i', b'd']),
-    WordBuffer::from_u8s([b'd', b'r', b'u', b'n', b'k']),
-    WordBuffer::from_u8s([b'd', b'r', b'y', b'e', b'r']),
-    WordBuffer::from_u8s([b'd', b'r', b'y', b'l', b'y']),
-    WordBuffer::from_u8s([b'd', b'u', b'c', b'h', b'y']),
-    WordBuffer::from_u8s([b'd', b'u', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'd', b'u', b'm', b'm', b'y']),
-    WordBuffer::from_u8s([b'd', b'u', b'm', b'p', b'y']),
-    WordBuffer::from_u8s([b'd', b'u', b'n', b'c', b'e']),
-    WordBuffer::from_u8s([b'd', b'u', b's', b'k', b'y']),
-    WordBuffer::from_u8s([b'd', b'u', b's', b't', b'y']),
-    WordBuffer::from_u8s([b'd', b'u', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'd', b'u', b'v', b'e', b't']),
-    WordBuffer::from_u8s([b'd', b'w', b'a', b'r', b'f']),
-    WordBuffer::from_u8s([b'd', b'w', b'e', b'l', b'l']),
-    WordBuffer::from_u8s([b'd', b'w', b'e', b'l', b't']),
-    WordBuffer::from_u8s([b'd', b'y', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'e', b'a', b'g', b'e', b'r']),
-    WordBuffer::from_u8s([b'e', b'a', b'g', b'l', b'e']),
-    WordBuffer::from_u8s([b'e', b'a', b'r', b'l', b'y']),
-    WordBuffer::from_u8s([b'e', b'a', b'r', b't', b'h']),
-    WordBuffer::from_u8s([b'e', b'a', b's', b'e', b'l']),
-    WordBuffer::from_u8s([b'e', b'a', b't', b'e', b'n']),
-    WordBuffer::from_u8s([b'e', b'a', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'e', b'b', b'o', b'n', b'y']),
-    WordBuffer::from_u8s([b'e', b'c', b'l', b'a', b't']),
-    WordBuffer::from_u8s([b'e', b'd', b'i', b'c', b't']),
-    WordBuffer::from_u8s([b'e', b'd', b'i', b'f', b'y']),
-    WordBuffer::from_u8s([b'e', b'e', b'r', b'i', b'e']),
-    WordBuffer::from_u8s([b'e', b'g', b'r', b'e', b't']),
-    WordBuffer::from_u8s([b'e', b'i', b'g', b'h', b't']),
-    WordBuffer::from_u8s([b'e', b'j', b'e', b'c', b't']),
-    WordBuffer::from_u8s([b'e', b'k', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'e', b'l', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b'e', b'l', b'b', b'o', b'w']),
-    WordBuffer::from_u8s([b'e', b'l', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'e', b'l', b'e', b'c', b't']),
-    WordBuffer::from_u8s([b'e', b'l', b'e', b'g', b'y']),
-    WordBuffer::from_u8s([b'e', b'l', b'f', b'i', b'n']),
-    WordBuffer::from_u8s([b'e', b'l', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b'e', b'l', b'i', b't', b'e']),
-    WordBuffer::from_u8s([b'e', b'l', b'o', b'p', b'e']),
-    WordBuffer::from_u8s([b'e', b'l', b'u', b'd', b'e']),
-    WordBuffer::from_u8s([b'e', b'm', b'a', b'i', b'l']),
-    WordBuffer::from_u8s([b'e', b'm', b'b', b'e', b'd']),
-    WordBuffer::from_u8s([b'e', b'm', b'b', b'e', b'r']),
-    WordBuffer::from_u8s([b'e', b'm', b'c', b'e', b'e']),
-    WordBuffer::from_u8s([b'e', b'm', b'p', b't', b'y']),
-    WordBuffer::from_u8s([b'e', b'n', b'a', b'c', b't']),
-    WordBuffer::from_u8s([b'e', b'n', b'd', b'o', b'w']),
-    WordBuffer::from_u8s([b'e', b'n', b'e', b'm', b'a']),
-    WordBuffer::from_u8s([b'e', b'n', b'e', b'm', b'y']),
-    WordBuffer::from_u8s([b'e', b'n', b'j', b'o', b'y']),
-    WordBuffer::from_u8s([b'e', b'n', b'n', b'u', b'i']),
-    WordBuffer::from_u8s([b'e', b'n', b's', b'u', b'e']),
-    WordBuffer::from_u8s([b'e', b'n', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'e', b'n', b't', b'r', b'y']),
-    WordBuffer::from_u8s([b'e', b'n', b'v', b'o', b'y']),
-    WordBuffer::from_u8s([b'e', b'p', b'o', b'c', b'h']),
-    WordBuffer::from_u8s([b'e', b'p', b'o', b'x', b'y']),
-    WordBuffer::from_u8s([b'e', b'q', b'u', b'a', b'l']),
-    WordBuffer::from_u8s([b'e', b'q', b'u', b'i', b'p']),
-    WordBuffer::from_u8s([b'e', b'r', b'a', b's', b'e']),
-    WordBuffer::from_u8s([b'e', b'r', b'e', b'c', b't']),
-    WordBuffer::from_u8s([b'e', b'r', b'o', b'd', b'e']),
-    WordBuffer::from_u8s([b'e', b'r', b'r', b'o', b'r']),
-    WordBuffer::from_u8s([b'e', b'r', b'u', b'p', b't']),
-    WordBuffer::from_u8s([b'e', b's', b's', b'a', b'y']),
-    WordBuffer::from_u8s([b'e', b's', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'e', b't', b'h', b'e', b'r']),
-    WordBuffer::from_u8s([b'e', b't', b'h', b'i', b'c']),
-    WordBuffer::from_u8s([b'e', b't', b'h', b'o', b's']),
-    WordBuffer::from_u8s([b'e', b't', b'u', b'd', b'e']),
-    WordBuffer::from_u8s([b'e', b'v', b'a', b'd', b'e']),
-    WordBuffer::from_u8s([b'e', b'v', b'e', b'n', b't']),
-    WordBuffer::from_u8s([b'e', b'v', b'e', b'r', b'y']),
-    WordBuffer::from_u8s([b'e', b'v', b'i', b'c', b't']),
-    WordBuffer::from_u8s([b'e', b'v', b'o', b'k', b'e']),
-    WordBuffer::from_u8s([b'e', b'x', b'a', b'c', b't']),
-    WordBuffer::from_u8s([b'e', b'x', b'a', b'l', b't']),
-    WordBuffer::from_u8s([b'e', b'x', b'c', b'e', b'l']),
-    WordBuffer::from_u8s([b'e', b'x', b'e', b'r', b't']),
-    WordBuffer::from_u8s([b'e', b'x', b'i', b'l', b'e']),
-    WordBuffer::from_u8s([b'e', b'x', b'i', b's', b't']),
-    WordBuffer::from_u8s([b'e', b'x', b'p', b'e', b'l']),
-    WordBuffer::from_u8s([b'e', b'x', b't', b'o', b'l']),
-    WordBuffer::from_u8s([b'e', b'x', b't', b'r', b'a']),
-    WordBuffer::from_u8s([b'e', b'x', b'u', b'l', b't']),
-    WordBuffer::from_u8s([b'e', b'y', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'f', b'a', b'b', b'l', b'e']),
-    WordBuffer::from_u8s([b'f', b'a', b'c', b'e', b't']),
-    WordBuffer::from_u8s([b'f', b'a', b'i', b'n', b't']),
-    WordBuffer::from_u8s([b'f', b'a', b'i', b'r', b'y']),
-    WordBuffer::from_u8s([b'f', b'a', b'i', b't', b'h']),
-    WordBuffer::from_u8s([b'f', b'a', b'l', b's', b'e']),
-    WordBuffer::from_u8s([b'f', b'a', b'n', b'c', b'y']),
-    WordBuffer::from_u8s([b'f', b'a', b'n', b'n', b'y']),
-    WordBuffer::from_u8s([b'f', b'a', b'r', b'c', b'e']),
-    WordBuffer::from_u8s([b'f', b'a', b't', b'a', b'l']),
-    WordBuffer::from_u8s([b'f', b'a', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'f', b'a', b'u', b'l', b't']),
-    WordBuffer::from_u8s([b'f', b'a', b'u', b'n', b'a']),
-    WordBuffer::from_u8s([b'f', b'a', b'v', b'o', b'r']),
-    WordBuffer::from_u8s([b'f', b'e', b'a', b's', b't']),
-    WordBuffer::from_u8s([b'f', b'e', b'c', b'a', b'l']),
-    WordBuffer::from_u8s([b'f', b'e', b'i', b'g', b'n']),
-    WordBuffer::from_u8s([b'f', b'e', b'l', b'l', b'a']),
-    WordBuffer::from_u8s([b'f', b'e', b'l', b'o', b'n']),
-    WordBuffer::from_u8s([b'f', b'e', b'm', b'm', b'e']),
-    WordBuffer::from_u8s([b'f', b'e', b'm', b'u', b'r']),
-    WordBuffer::from_u8s([b'f', b'e', b'n', b'c', b'e']),
-    WordBuffer::from_u8s([b'f', b'e', b'r', b'a', b'l']),
-    WordBuffer::from_u8s([b'f', b'e', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'f', b'e', b't', b'a', b'l']),
-    WordBuffer::from_u8s([b'f', b'e', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'f', b'e', b't', b'i', b'd']),
-    WordBuffer::from_u8s([b'f', b'e', b't', b'u', b's']),
-    WordBuffer::from_u8s([b'f', b'e', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'f', b'e', b'w', b'e', b'r']),
-    WordBuffer::from_u8s([b'f', b'i', b'b', b'e', b'r']),
-    WordBuffer::from_u8s([b'f', b'i', b'b', b'r', b'e']),
-    WordBuffer::from_u8s([b'f', b'i', b'c', b'u', b's']),
-    WordBuffer::from_u8s([b'f', b'i', b'e', b'l', b'd']),
-    WordBuffer::from_u8s([b'f', b'i', b'e', b'n', b'd']),
-    WordBuffer::from_u8s([b'f', b'i', b'e', b'r', b'y']),
-    WordBuffer::from_u8s([b'f', b'i', b'f', b't', b'h']),
-    WordBuffer::from_u8s([b'f', b'i', b'f', b't', b'y']),
-    WordBuffer::from_u8s([b'f', b'i', b'g', b'h', b't']),
-    WordBuffer::from_u8s([b'f', b'i', b'l', b'e', b'r']),
-    WordBuffer::from_u8s([b'f', b'i', b'l', b'e', b't']),
-    WordBuffer::from_u8s([b'f', b'i', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'f', b'i', b'l', b'm', b'y']),
-    WordBuffer::from_u8s([b'f', b'i', b'l', b't', b'h']),
-    WordBuffer::from_u8s([b'f', b'i', b'n', b'a', b'l']),
-    WordBuffer::from_u8s([b'f', b'i', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'f', b'i', b'n', b'e', b'r']),
-    WordBuffer::from_u8s([b'f', b'i', b'r', b's', b't']),
-    WordBuffer::from_u8s([b'f', b'i', b's', b'h', b'y']),
-    WordBuffer::from_u8s([b'f', b'i', b'x', b'e', b'r']),
-    WordBuffer::from_u8s([b'f', b'i', b'z', b'z', b'y']),
-    WordBuffer::from_u8s([b'f', b'j', b'o', b'r', b'd']),
-    WordBuffer::from_u8s([b'f', b'l', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b'f', b'l', b'a', b'i', b'l']),
-    WordBuffer::from_u8s([b'f', b'l', b'a', b'i', b'r']),
-    WordBuffer::from_u8s([b'f', b'l', b'a', b'k', b'e']),
-    WordBuffer::from_u8s([b'f', b'l', b'a', b'k', b'y']),
-    WordBuffer::from_u8s([b'f', b'l', b'a', b'm', b'e']),
-    WordBuffer::from_u8s([b'f', b'l', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b'f', b'l', b'a', b'r', b'e']),
-    WordBuffer::from_u8s([b'f', b'l', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b'f', b'l', b'a', b's', b'k']),
-    WordBuffer::from_u8s([b'f', b'l', b'e', b'c', b'k']),
-    WordBuffer::from_u8s([b'f', b'l', b'e', b'e', b't']),
-    WordBuffer::from_u8s([b'f', b'l', b'e', b's', b'h']),
-    WordBuffer::from_u8s([b'f', b'l', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b'f', b'l', b'i', b'e', b'r']),
-    WordBuffer::from_u8s([b'f', b'l', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'f', b'l', b'i', b'n', b't']),
-    WordBuffer::from_u8s([b'f', b'l', b'i', b'r', b't']),
-    WordBuffer::from_u8s([b'f', b'l', b'o', b'a', b't']),
-    WordBuffer::from_u8s([b'f', b'l', b'o', b'c', b'k']),
-    WordBuffer::from_u8s([b'f', b'l', b'o', b'o', b'd']),
-    WordBuffer::from_u8s([b'f', b'l', b'o', b'o', b'r']),
-    WordBuffer::from_u8s([b'f', b'l', b'o', b'r', b'a']),
-    WordBuffer::from_u8s([b'f', b'l', b'o', b's', b's']),
-    WordBuffer::from_u8s([b'f', b'l', b'o', b'u', b'r']),
-    WordBuffer::from_u8s([b'f', b'l', b'o', b'u', b't']),
-    WordBuffer::from_u8s([b'f', b'l', b'o', b'w', b'n']),
-    WordBuffer::from_u8s([b'f', b'l', b'u', b'f', b'f']),
-    WordBuffer::from_u8s([b'f', b'l', b'u', b'i', b'd']),
-    WordBuffer::from_u8s([b'f', b'l', b'u', b'k', b'e']),
-    WordBuffer::from_u8s([b'f', b'l', b'u', b'm', b'e']),
-    WordBuffer::from_u8s([b'f', b'l', b'u', b'n', b'g']),
-    WordBuffer::from_u8s([b'f', b'l', b'u', b'n', b'k']),
-    WordBuffer::from_u8s([b'f', b'l', b'u', b's', b'h']),
-    WordBuffer::from_u8s([b'f', b'l', b'u', b't', b'e']),
-    WordBuffer::from_u8s([b'f', b'l', b'y', b'e', b'r']),
-    WordBuffer::from_u8s([b'f', b'o', b'a', b'm', b'y']),
-    WordBuffer::from_u8s([b'f', b'o', b'c', b'a', b'l']),
-    WordBuffer::from_u8s([b'f', b'o', b'c', b'u', b's']),
-    WordBuffer::from_u8s([b'f', b'o', b'g', b'g', b'y']),
-    WordBuffer::from_u8s([b'f', b'o', b'i', b's', b't']),
-    WordBuffer::from_u8s([b'f', b'o', b'l', b'i', b'o']),
-    WordBuffer::from_u8s([b'f', b'o', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'f', b'o', b'r', b'a', b'y']),
-    WordBuffer::from_u8s([b'f', b'o', b'r', b'c', b'e']),
-    WordBuffer::from_u8s([b'f', b'o', b'r', b'g', b'e']),
-    WordBuffer::from_u8s([b'f', b'o', b'r', b'g', b'o']),
-    WordBuffer::from_u8s([b'f', b'o', b'r', b't', b'e']),
-    WordBuffer::from_u8s([b'f', b'o', b'r', b't', b'h']),
-    WordBuffer::from_u8s([b'f', b'o', b'r', b't', b'y']),
-    WordBuffer::from_u8s([b'f', b'o', b'r', b'u', b'm']),
-    WordBuffer::from_u8s([b'f', b'o', b'u', b'n', b'd']),
-    WordBuffer::from_u8s([b'f', b'o', b'y', b'e', b'r']),
-    WordBuffer::from_u8s([b'f', b'r', b'a', b'i', b'l']),
-    WordBuffer::from_u8s([b'f', b'r', b'a', b'm', b'e']),
-    WordBuffer::from_u8s([b'f', b'r', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b'f', b'r', b'a', b'u', b'd']),
-    WordBuffer::from_u8s([b'f', b'r', b'e', b'a', b'k']),
-    WordBuffer::from_u8s([b'f', b'r', b'e', b'e', b'd']),
-    WordBuffer::from_u8s([b'f', b'r', b'e', b'e', b'r']),
-    WordBuffer::from_u8s([b'f', b'r', b'e', b's', b'h']),
-    WordBuffer::from_u8s([b'f', b'r', b'i', b'a', b'r']),
-    WordBuffer::from_u8s([b'f', b'r', b'i', b'e', b'd']),
-    WordBuffer::from_u8s([b'f', b'r', b'i', b'l', b'l']),
-    WordBuffer::from_u8s([b'f', b'r', b'i', b's', b'k']),
-    WordBuffer::from_u8s([b'f', b'r', b'i', b't', b'z']),
-    WordBuffer::from_u8s([b'f', b'r', b'o', b'c', b'k']),
-    WordBuffer::from_u8s([b'f', b'r', b'o', b'n', b'd']),
-    WordBuffer::from_u8s([b'f', b'r', b'o', b'n', b't']),
-    WordBuffer::from_u8s([b'f', b'r', b'o', b's', b't']),
-    WordBuffer::from_u8s([b'f', b'r', b'o', b't', b'h']),
-    WordBuffer::from_u8s([b'f', b'r', b'o', b'w', b'n']),
-    WordBuffer::from_u8s([b'f', b'r', b'o', b'z', b'e']),
-    WordBuffer::from_u8s([b'f', b'r', b'u', b'i', b't']),
-    WordBuffer::from_u8s([b'f', b'u', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'f', b'u', b'g', b'u', b'e']),
-    WordBuffer::from_u8s([b'f', b'u', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'f', b'u', b'n', b'g', b'i']),
-    WordBuffer::from_u8s([b'f', b'u', b'n', b'k', b'y']),
-    WordBuffer::from_u8s([b'f', b'u', b'n', b'n', b'y']),
-    WordBuffer::from_u8s([b'f', b'u', b'r', b'o', b'r']),
-    WordBuffer::from_u8s([b'f', b'u', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'f', b'u', b's', b's', b'y']),
-    WordBuffer::from_u8s([b'f', b'u', b'z', b'z', b'y']),
-    WordBuffer::from_u8s([b'g', b'a', b'f', b'f', b'e']),
-    WordBuffer::from_u8s([b'g', b'a', b'i', b'l', b'y']),
-    WordBuffer::from_u8s([b'g', b'a', b'm', b'e', b'r']),
-    WordBuffer::from_u8s([b'g', b'a', b'm', b'm', b'a']),
-    WordBuffer::from_u8s([b'g', b'a', b'm', b'u', b't']),
-    WordBuffer::from_u8s([b'g', b'a', b's', b's', b'y']),
-    WordBuffer::from_u8s([b'g', b'a', b'u', b'd', b'y']),
-    WordBuffer::from_u8s([b'g', b'a', b'u', b'g', b'e']),
-    WordBuffer::from_u8s([b'g', b'a', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b'g', b'a', b'u', b'z', b'e']),
-    WordBuffer::from_u8s([b'g', b'a', b'v', b'e', b'l']),
-    WordBuffer::from_u8s([b'g', b'a', b'w', b'k', b'y']),
-    WordBuffer::from_u8s([b'g', b'a', b'y', b'e', b'r']),
-    WordBuffer::from_u8s([b'g', b'a', b'y', b'l', b'y']),
-    WordBuffer::from_u8s([b'g', b'a', b'z', b'e', b'r']),
-    WordBuffer::from_u8s([b'g', b'e', b'c', b'k', b'o']),
-    WordBuffer::from_u8s([b'g', b'e', b'e', b'k', b'y']),
-    WordBuffer::from_u8s([b'g', b'e', b'e', b's', b'e']),
-    WordBuffer::from_u8s([b'g', b'e', b'n', b'i', b'e']),
-    WordBuffer::from_u8s([b'g', b'e', b'n', b'r', b'e']),
-    WordBuffer::from_u8s([b'g', b'h', b'o', b's', b't']),
-    WordBuffer::from_u8s([b'g', b'h', b'o', b'u', b'l']),
-    WordBuffer::from_u8s([b'g', b'i', b'a', b'n', b't']),
-    WordBuffer::from_u8s([b'g', b'i', b'd', b'd', b'y']),
-    WordBuffer::from_u8s([b'g', b'i', b'p', b's', b'y']),
-    WordBuffer::from_u8s([b'g', b'i', b'r', b'l', b'y']),
-    WordBuffer::from_u8s([b'g', b'i', b'r', b't', b'h']),
-    WordBuffer::from_u8s([b'g', b'i', b'v', b'e', b'n']),
-    WordBuffer::from_u8s([b'g', b'i', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'g', b'l', b'a', b'd', b'e']),
-    WordBuffer::from_u8s([b'g', b'l', b'a', b'n', b'd']),
-    WordBuffer::from_u8s([b'g', b'l', b'a', b'r', b'e']),
-    WordBuffer::from_u8s([b'g', b'l', b'a', b's', b's']),
-    WordBuffer::from_u8s([b'g', b'l', b'a', b'z', b'e']),
-    WordBuffer::from_u8s([b'g', b'l', b'e', b'a', b'm']),
-    WordBuffer::from_u8s([b'g', b'l', b'e', b'a', b'n']),
-    WordBuffer::from_u8s([b'g', b'l', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b'g', b'l', b'i', b'n', b't']),
-    WordBuffer::from_u8s([b'g', b'l', b'o', b'a', b't']),
-    WordBuffer::from_u8s([b'g', b'l', b'o', b'b', b'e']),
-    WordBuffer::from_u8s([b'g', b'l', b'o', b'o', b'm']),
-    WordBuffer::from_u8s([b'g', b'l', b'o', b'r', b'y']),
-    WordBuffer::from_u8s([b'g', b'l', b'o', b's', b's']),
-    WordBuffer::from_u8s([b'g', b'l', b'o', b'v', b'e']),
-    WordBuffer::from_u8s([b'g', b'l', b'y', b'p', b'h']),
-    WordBuffer::from_u8s([b'g', b'n', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b'g', b'n', b'o', b'm', b'e']),
-    WordBuffer::from_u8s([b'g', b'o', b'd', b'l', b'y']),
-    WordBuffer::from_u8s([b'g', b'o', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'g', b'o', b'l', b'e', b'm']),
-    WordBuffer::from_u8s([b'g', b'o', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'g', b'o', b'n', b'a', b'd']),
-    WordBuffer::from_u8s([b'g', b'o', b'n', b'e', b'r']),
-    WordBuffer::from_u8s([b'g', b'o', b'o', b'd', b'y']),
-    WordBuffer::from_u8s([b'g', b'o', b'o', b'e', b'y']),
-    WordBuffer::from_u8s([b'g', b'o', b'o', b'f', b'y']),
-    WordBuffer::from_u8s([b'g', b'o', b'o', b's', b'e']),
-    WordBuffer::from_u8s([b'g', b'o', b'r', b'g', b'e']),
-    WordBuffer::from_u8s([b'g', b'o', b'u', b'g', b'e']),
-    WordBuffer::from_u8s([b'g', b'o', b'u', b'r', b'd']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'c', b'e']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'd', b'e']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'f', b't']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'i', b'l']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'i', b'n']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'n', b'd']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'n', b't']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'p', b'e']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'p', b'h']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b's', b'p']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b's', b's']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'v', b'y']),
-    WordBuffer::from_u8s([b'g', b'r', b'a', b'z', b'e']),
-    WordBuffer::from_u8s([b'g', b'r', b'e', b'a', b't']),
-    WordBuffer::from_u8s([b'g', b'r', b'e', b'e', b'd']),
-    WordBuffer::from_u8s([b'g', b'r', b'e', b'e', b'n']),
-    WordBuffer::from_u8s([b'g', b'r', b'e', b'e', b't']),
-    WordBuffer::from_u8s([b'g', b'r', b'i', b'e', b'f']),
-    WordBuffer::from_u8s([b'g', b'r', b'i', b'l', b'l']),
-    WordBuffer::from_u8s([b'g', b'r', b'i', b'm', b'e']),
-    WordBuffer::from_u8s([b'g', b'r', b'i', b'm', b'y']),
-    WordBuffer::from_u8s([b'g', b'r', b'i', b'n', b'd']),
-    WordBuffer::from_u8s([b'g', b'r', b'i', b'p', b'e']),
-    WordBuffer::from_u8s([b'g', b'r', b'o', b'a', b'n']),
-    WordBuffer::from_u8s([b'g', b'r', b'o', b'i', b'n']),
-    WordBuffer::from_u8s([b'g', b'r', b'o', b'o', b'm']),
-    WordBuffer::from_u8s([b'g', b'r', b'o', b'p', b'e']),
-    WordBuffer::from_u8s([b'g', b'r', b'o', b's', b's']),
-    WordBuffer::from_u8s([b'g', b'r', b'o', b'u', b'p']),
-    WordBuffer::from_u8s([b'g', b'r', b'o', b'u', b't']),
-    WordBuffer::from_u8s([b'g', b'r', b'o', b'v', b'e']),
-    WordBuffer::from_u8s([b'g', b'r', b'o', b'w', b'l']),
-    WordBuffer::from_u8s([b'g', b'r', b'o', b'w', b'n']),
-    WordBuffer::from_u8s([b'g', b'r', b'u', b'e', b'l']),
-    WordBuffer::from_u8s([b'g', b'r', b'u', b'f', b'f']),
-    WordBuffer::from_u8s([b'g', b'r', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b'g', b'u', b'a', b'r', b'd']),
-    WordBuffer::from_u8s([b'g', b'u', b'a', b'v', b'a']),
-    WordBuffer::from_u8s([b'g', b'u', b'e', b's', b's']),
-    WordBuffer::from_u8s([b'g', b'u', b'e', b's', b't']),
-    WordBuffer::from_u8s([b'g', b'u', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b'g', b'u', b'i', b'l', b'd']),
-    WordBuffer::from_u8s([b'g', b'u', b'i', b'l', b'e']),
-    WordBuffer::from_u8s([b'g', b'u', b'i', b'l', b't']),
-    WordBuffer::from_u8s([b'g', b'u', b'i', b's', b'e']),
-    WordBuffer::from_u8s([b'g', b'u', b'l', b'c', b'h']),
-    WordBuffer::from_u8s([b'g', b'u', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'g', b'u', b'm', b'b', b'o']),
-    WordBuffer::from_u8s([b'g', b'u', b'm', b'm', b'y']),
-    WordBuffer::from_u8s([b'g', b'u', b'p', b'p', b'y']),
-    WordBuffer::from_u8s([b'g', b'u', b's', b't', b'o']),
-    WordBuffer::from_u8s([b'g', b'u', b's', b't', b'y']),
-    WordBuffer::from_u8s([b'g', b'y', b'p', b's', b'y']),
-    WordBuffer::from_u8s([b'h', b'a', b'b', b'i', b't']),
-    WordBuffer::from_u8s([b'h', b'a', b'i', b'r', b'y']),
-    WordBuffer::from_u8s([b'h', b'a', b'l', b'v', b'e']),
-    WordBuffer::from_u8s([b'h', b'a', b'n', b'd', b'y']),
-    WordBuffer::from_u8s([b'h', b'a', b'p', b'p', b'y']),
-    WordBuffer::from_u8s([b'h', b'a', b'r', b'd', b'y']),
-    WordBuffer::from_u8s([b'h', b'a', b'r', b'e', b'm']),
-    WordBuffer::from_u8s([b'h', b'a', b'r', b'p', b'y']),
-    WordBuffer::from_u8s([b'h', b'a', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'h', b'a', b'r', b's', b'h']),
-    WordBuffer::from_u8s([b'h', b'a', b's', b't', b'e']),
-    WordBuffer::from_u8s([b'h', b'a', b's', b't', b'y']),
-    WordBuffer::from_u8s([b'h', b'a', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'h', b'a', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'h', b'a', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b'h', b'a', b'u', b't', b'e']),
-    WordBuffer::from_u8s([b'h', b'a', b'v', b'e', b'n']),
-    WordBuffer::from_u8s([b'h', b'a', b'v', b'o', b'c']),
-    WordBuffer::from_u8s([b'h', b'a', b'z', b'e', b'l']),
-    WordBuffer::from_u8s([b'h', b'e', b'a', b'd', b'y']),
-    WordBuffer::from_u8s([b'h', b'e', b'a', b'r', b'd']),
-    WordBuffer::from_u8s([b'h', b'e', b'a', b'r', b't']),
-    WordBuffer::from_u8s([b'h', b'e', b'a', b't', b'h']),
-    WordBuffer::from_u8s([b'h', b'e', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b'h', b'e', b'a', b'v', b'y']),
-    WordBuffer::from_u8s([b'h', b'e', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'h', b'e', b'f', b't', b'y']),
-    WordBuffer::from_u8s([b'h', b'e', b'i', b's', b't']),
-    WordBuffer::from_u8s([b'h', b'e', b'l', b'i', b'x']),
-    WordBuffer::from_u8s([b'h', b'e', b'l', b'l', b'o']),
-    WordBuffer::from_u8s([b'h', b'e', b'n', b'c', b'e']),
-    WordBuffer::from_u8s([b'h', b'e', b'r', b'o', b'n']),
-    WordBuffer::from_u8s([b'h', b'i', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'h', b'i', b'n', b'g', b'e']),
-    WordBuffer::from_u8s([b'h', b'i', b'p', b'p', b'o']),
-    WordBuffer::from_u8s([b'h', b'i', b'p', b'p', b'y']),
-    WordBuffer::from_u8s([b'h', b'i', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'h', b'o', b'a', b'r', b'd']),
-    WordBuffer::from_u8s([b'h', b'o', b'b', b'b', b'y']),
-    WordBuffer::from_u8s([b'h', b'o', b'i', b's', b't']),
-    WordBuffer::from_u8s([b'h', b'o', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'h', b'o', b'm', b'e', b'r']),
-    WordBuffer::from_u8s([b'h', b'o', b'n', b'e', b'y']),
-    WordBuffer::from_u8s([b'h', b'o', b'n', b'o', b'r']),
-    WordBuffer::from_u8s([b'h', b'o', b'r', b'd', b'e']),
-    WordBuffer::from_u8s([b'h', b'o', b'r', b'n', b'y']),
-    WordBuffer::from_u8s([b'h', b'o', b'r', b's', b'e']),
-    WordBuffer::from_u8s([b'h', b'o', b't', b'e', b'l']),
-    WordBuffer::from_u8s([b'h', b'o', b't', b'l', b'y']),
-    WordBuffer::from_u8s([b'h', b'o', b'u', b'n', b'd']),
-    WordBuffer::from_u8s([b'h', b'o', b'u', b's', b'e']),
-    WordBuffer::from_u8s([b'h', b'o', b'v', b'e', b'l']),
-    WordBuffer::from_u8s([b'h', b'o', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'h', b'o', b'w', b'd', b'y']),
-    WordBuffer::from_u8s([b'h', b'u', b'm', b'a', b'n']),
-    WordBuffer::from_u8s([b'h', b'u', b'm', b'i', b'd']),
-    WordBuffer::from_u8s([b'h', b'u', b'm', b'o', b'r']),
-    WordBuffer::from_u8s([b'h', b'u', b'm', b'p', b'h']),
-    WordBuffer::from_u8s([b'h', b'u', b'm', b'u', b's']),
-    WordBuffer::from_u8s([b'h', b'u', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'h', b'u', b'n', b'k', b'y']),
-    WordBuffer::from_u8s([b'h', b'u', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'h', b'u', b's', b'k', b'y']),
-    WordBuffer::from_u8s([b'h', b'u', b's', b's', b'y']),
-    WordBuffer::from_u8s([b'h', b'u', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'h', b'y', b'd', b'r', b'o']),
-    WordBuffer::from_u8s([b'h', b'y', b'e', b'n', b'a']),
-    WordBuffer::from_u8s([b'h', b'y', b'm', b'e', b'n']),
-    WordBuffer::from_u8s([b'h', b'y', b'p', b'e', b'r']),
-    WordBuffer::from_u8s([b'i', b'c', b'i', b'l', b'y']),
-    WordBuffer::from_u8s([b'i', b'c', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'i', b'd', b'e', b'a', b'l']),
-    WordBuffer::from_u8s([b'i', b'd', b'i', b'o', b'm']),
-    WordBuffer::from_u8s([b'i', b'd', b'i', b'o', b't']),
-    WordBuffer::from_u8s([b'i', b'd', b'l', b'e', b'r']),
-    WordBuffer::from_u8s([b'i', b'd', b'y', b'l', b'l']),
-    WordBuffer::from_u8s([b'i', b'g', b'l', b'o', b'o']),
-    WordBuffer::from_u8s([b'i', b'l', b'i', b'a', b'c']),
-    WordBuffer::from_u8s([b'i', b'm', b'a', b'g', b'e']),
-    WordBuffer::from_u8s([b'i', b'm', b'b', b'u', b'e']),
-    WordBuffer::from_u8s([b'i', b'm', b'p', b'e', b'l']),
-    WordBuffer::from_u8s([b'i', b'm', b'p', b'l', b'y']),
-    WordBuffer::from_u8s([b'i', b'n', b'a', b'n', b'e']),
-    WordBuffer::from_u8s([b'i', b'n', b'b', b'o', b'x']),
-    WordBuffer::from_u8s([b'i', b'n', b'c', b'u', b'r']),
-    WordBuffer::from_u8s([b'i', b'n', b'd', b'e', b'x']),
-    WordBuffer::from_u8s([b'i', b'n', b'e', b'p', b't']),
-    WordBuffer::from_u8s([b'i', b'n', b'e', b'r', b't']),
-    WordBuffer::from_u8s([b'i', b'n', b'f', b'e', b'r']),
-    WordBuffer::from_u8s([b'i', b'n', b'g', b'o', b't']),
-    WordBuffer::from_u8s([b'i', b'n', b'l', b'a', b'y']),
-    WordBuffer::from_u8s([b'i', b'n', b'l', b'e', b't']),
-    WordBuffer::from_u8s([b'i', b'n', b'n', b'e', b'r']),
-    WordBuffer::from_u8s([b'i', b'n', b'p', b'u', b't']),
-    WordBuffer::from_u8s([b'i', b'n', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'i', b'n', b't', b'r', b'o']),
-    WordBuffer::from_u8s([b'i', b'o', b'n', b'i', b'c']),
-    WordBuffer::from_u8s([b'i', b'r', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b'i', b'r', b'o', b'n', b'y']),
-    WordBuffer::from_u8s([b'i', b's', b'l', b'e', b't']),
-    WordBuffer::from_u8s([b'i', b's', b's', b'u', b'e']),
-    WordBuffer::from_u8s([b'i', b't', b'c', b'h', b'y']),
-    WordBuffer::from_u8s([b'i', b'v', b'o', b'r', b'y']),
-    WordBuffer::from_u8s([b'j', b'a', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b'j', b'a', b'z', b'z', b'y']),
-    WordBuffer::from_u8s([b'j', b'e', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'j', b'e', b'r', b'k', b'y']),
-    WordBuffer::from_u8s([b'j', b'e', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'j', b'e', b'w', b'e', b'l']),
-    WordBuffer::from_u8s([b'j', b'i', b'f', b'f', b'y']),
-    WordBuffer::from_u8s([b'j', b'o', b'i', b'n', b't']),
-    WordBuffer::from_u8s([b'j', b'o', b'i', b's', b't']),
-    WordBuffer::from_u8s([b'j', b'o', b'k', b'e', b'r']),
-    WordBuffer::from_u8s([b'j', b'o', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'j', b'o', b'u', b's', b't']),
-    WordBuffer::from_u8s([b'j', b'u', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'j', b'u', b'i', b'c', b'e']),
-    WordBuffer::from_u8s([b'j', b'u', b'i', b'c', b'y']),
-    WordBuffer::from_u8s([b'j', b'u', b'm', b'b', b'o']),
-    WordBuffer::from_u8s([b'j', b'u', b'm', b'p', b'y']),
-    WordBuffer::from_u8s([b'j', b'u', b'n', b't', b'a']),
-    WordBuffer::from_u8s([b'j', b'u', b'n', b't', b'o']),
-    WordBuffer::from_u8s([b'j', b'u', b'r', b'o', b'r']),
-    WordBuffer::from_u8s([b'k', b'a', b'p', b'p', b'a']),
-    WordBuffer::from_u8s([b'k', b'a', b'r', b'm', b'a']),
-    WordBuffer::from_u8s([b'k', b'a', b'y', b'a', b'k']),
-    WordBuffer::from_u8s([b'k', b'e', b'b', b'a', b'b']),
-    WordBuffer::from_u8s([b'k', b'h', b'a', b'k', b'i']),
-    WordBuffer::from_u8s([b'k', b'i', b'n', b'k', b'y']),
-    WordBuffer::from_u8s([b'k', b'i', b'o', b's', b'k']),
-    WordBuffer::from_u8s([b'k', b'i', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'k', b'n', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b'k', b'n', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b'k', b'n', b'e', b'a', b'd']),
-    WordBuffer::from_u8s([b'k', b'n', b'e', b'e', b'd']),
-    WordBuffer::from_u8s([b'k', b'n', b'e', b'e', b'l']),
-    WordBuffer::from_u8s([b'k', b'n', b'e', b'l', b't']),
-    WordBuffer::from_u8s([b'k', b'n', b'i', b'f', b'e']),
-    WordBuffer::from_u8s([b'k', b'n', b'o', b'c', b'k']),
-    WordBuffer::from_u8s([b'k', b'n', b'o', b'l', b'l']),
-    WordBuffer::from_u8s([b'k', b'n', b'o', b'w', b'n']),
-    WordBuffer::from_u8s([b'k', b'o', b'a', b'l', b'a']),
-    WordBuffer::from_u8s([b'k', b'r', b'i', b'l', b'l']),
-    WordBuffer::from_u8s([b'l', b'a', b'b', b'e', b'l']),
-    WordBuffer::from_u8s([b'l', b'a', b'b', b'o', b'r']),
-    WordBuffer::from_u8s([b'l', b'a', b'd', b'e', b'n']),
-    WordBuffer::from_u8s([b'l', b'a', b'd', b'l', b'e']),
-    WordBuffer::from_u8s([b'l', b'a', b'g', b'e', b'r']),
-    WordBuffer::from_u8s([b'l', b'a', b'n', b'c', b'e']),
-    WordBuffer::from_u8s([b'l', b'a', b'n', b'k', b'y']),
-    WordBuffer::from_u8s([b'l', b'a', b'p', b'e', b'l']),
-    WordBuffer::from_u8s([b'l', b'a', b'p', b's', b'e']),
-    WordBuffer::from_u8s([b'l', b'a', b'r', b'g', b'e']),
-    WordBuffer::from_u8s([b'l', b'a', b'r', b'v', b'a']),
-    WordBuffer::from_u8s([b'l', b'a', b's', b's', b'o']),
-    WordBuffer::from_u8s([b'l', b'a', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'l', b'a', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'l', b'a', b't', b'h', b'e']),
-    WordBuffer::from_u8s([b'l', b'a', b't', b't', b'e']),
-    WordBuffer::from_u8s([b'l', b'a', b'u', b'g', b'h']),
-    WordBuffer::from_u8s([b'l', b'a', b'y', b'e', b'r']),
-    WordBuffer::from_u8s([b'l', b'e', b'a', b'c', b'h']),
-    WordBuffer::from_u8s([b'l', b'e', b'a', b'f', b'y']),
-    WordBuffer::from_u8s([b'l', b'e', b'a', b'k', b'y']),
-    WordBuffer::from_u8s([b'l', b'e', b'a', b'n', b't']),
-    WordBuffer::from_u8s([b'l', b'e', b'a', b'p', b't']),
-    WordBuffer::from_u8s([b'l', b'e', b'a', b'r', b'n']),
-    WordBuffer::from_u8s([b'l', b'e', b'a', b's', b'e']),
-    WordBuffer::from_u8s([b'l', b'e', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b'l', b'e', b'a', b's', b't']),
-    WordBuffer::from_u8s([b'l', b'e', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b'l', b'e', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'l', b'e', b'e', b'c', b'h']),
-    WordBuffer::from_u8s([b'l', b'e', b'e', b'r', b'y']),
-    WordBuffer::from_u8s([b'l', b'e', b'f', b't', b'y']),
-    WordBuffer::from_u8s([b'l', b'e', b'g', b'a', b'l']),
-    WordBuffer::from_u8s([b'l', b'e', b'g', b'g', b'y']),
-    WordBuffer::from_u8s([b'l', b'e', b'm', b'o', b'n']),
-    WordBuffer::from_u8s([b'l', b'e', b'm', b'u', b'r']),
-    WordBuffer::from_u8s([b'l', b'e', b'p', b'e', b'r']),
-    WordBuffer::from_u8s([b'l', b'e', b'v', b'e', b'l']),
-    WordBuffer::from_u8s([b'l', b'e', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'l', b'i', b'b', b'e', b'l']),
-    WordBuffer::from_u8s([b'l', b'i', b'e', b'g', b'e']),
-    WordBuffer::from_u8s([b'l', b'i', b'g', b'h', b't']),
-    WordBuffer::from_u8s([b'l', b'i', b'k', b'e', b'n']),
-    WordBuffer::from_u8s([b'l', b'i', b'l', b'a', b'c']),
-    WordBuffer::from_u8s([b'l', b'i', b'm', b'b', b'o']),
-    WordBuffer::from_u8s([b'l', b'i', b'm', b'i', b't']),
-    WordBuffer::from_u8s([b'l', b'i', b'n', b'e', b'n']),
-    WordBuffer::from_u8s([b'l', b'i', b'n', b'e', b'r']),
-    WordBuffer::from_u8s([b'l', b'i', b'n', b'g', b'o']),
-    WordBuffer::from_u8s([b'l', b'i', b'p', b'i', b'd']),
-    WordBuffer::from_u8s([b'l', b'i', b't', b'h', b'e']),
-    WordBuffer::from_u8s([b'l', b'i', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'l', b'i', b'v', b'i', b'd']),
-    WordBuffer::from_u8s([b'l', b'l', b'a', b'm', b'a']),
-    WordBuffer::from_u8s([b'l', b'o', b'a', b'm', b'y']),
-    WordBuffer::from_u8s([b'l', b'o', b'a', b't', b'h']),
-    WordBuffer::from_u8s([b'l', b'o', b'b', b'b', b'y']),
-    WordBuffer::from_u8s([b'l', b'o', b'c', b'a', b'l']),
-    WordBuffer::from_u8s([b'l', b'o', b'c', b'u', b's']),
-    WordBuffer::from_u8s([b'l', b'o', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'l', b'o', b'f', b't', b'y']),
-    WordBuffer::from_u8s([b'l', b'o', b'g', b'i', b'c']),
-    WordBuffer::from_u8s([b'l', b'o', b'g', b'i', b'n']),
-    WordBuffer::from_u8s([b'l', b'o', b'o', b'p', b'y']),
-    WordBuffer::from_u8s([b'l', b'o', b'o', b's', b'e']),
-    WordBuffer::from_u8s([b'l', b'o', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'l', b'o', b's', b'e', b'r']),
-    WordBuffer::from_u8s([b'l', b'o', b'u', b's', b'e']),
-    WordBuffer::from_u8s([b'l', b'o', b'u', b's', b'y']),
-    WordBuffer::from_u8s([b'l', b'o', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'l', b'o', b'w', b'e', b'r']),
-    WordBuffer::from_u8s([b'l', b'o', b'w', b'l', b'y']),
-    WordBuffer::from_u8s([b'l', b'o', b'y', b'a', b'l']),
-    WordBuffer::from_u8s([b'l', b'u', b'c', b'i', b'd']),
-    WordBuffer::from_u8s([b'l', b'u', b'c', b'k', b'y']),
-    WordBuffer::from_u8s([b'l', b'u', b'm', b'e', b'n']),
-    WordBuffer::from_u8s([b'l', b'u', b'm', b'p', b'y']),
-    WordBuffer::from_u8s([b'l', b'u', b'n', b'a', b'r']),
-    WordBuffer::from_u8s([b'l', b'u', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'l', b'u', b'n', b'g', b'e']),
-    WordBuffer::from_u8s([b'l', b'u', b'p', b'u', b's']),
-    WordBuffer::from_u8s([b'l', b'u', b'r', b'c', b'h']),
-    WordBuffer::from_u8s([b'l', b'u', b'r', b'i', b'd']),
-    WordBuffer::from_u8s([b'l', b'u', b's', b't', b'y']),
-    WordBuffer::from_u8s([b'l', b'y', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'l', b'y', b'm', b'p', b'h']),
-    WordBuffer::from_u8s([b'l', b'y', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'l', b'y', b'r', b'i', b'c']),
-    WordBuffer::from_u8s([b'm', b'a', b'c', b'a', b'w']),
-    WordBuffer::from_u8s([b'm', b'a', b'c', b'h', b'o']),
-    WordBuffer::from_u8s([b'm', b'a', b'c', b'r', b'o']),
-    WordBuffer::from_u8s([b'm', b'a', b'd', b'a', b'm']),
-    WordBuffer::from_u8s([b'm', b'a', b'd', b'l', b'y']),
-    WordBuffer::from_u8s([b'm', b'a', b'f', b'i', b'a']),
-    WordBuffer::from_u8s([b'm', b'a', b'g', b'i', b'c']),
-    WordBuffer::from_u8s([b'm', b'a', b'g', b'm', b'a']),
-    WordBuffer::from_u8s([b'm', b'a', b'i', b'z', b'e']),
-    WordBuffer::from_u8s([b'm', b'a', b'j', b'o', b'r']),
-    WordBuffer::from_u8s([b'm', b'a', b'k', b'e', b'r']),
-    WordBuffer::from_u8s([b'm', b'a', b'm', b'b', b'o']),
-    WordBuffer::from_u8s([b'm', b'a', b'm', b'm', b'a']),
-    WordBuffer::from_u8s([b'm', b'a', b'm', b'm', b'y']),
-    WordBuffer::from_u8s([b'm', b'a', b'n', b'g', b'a']),
-    WordBuffer::from_u8s([b'm', b'a', b'n', b'g', b'e']),
-    WordBuffer::from_u8s([b'm', b'a', b'n', b'g', b'o']),
-    WordBuffer::from_u8s([b'm', b'a', b'n', b'g', b'y']),
-    WordBuffer::from_u8s([b'm', b'a', b'n', b'i', b'a']),
-    WordBuffer::from_u8s([b'm', b'a', b'n', b'i', b'c']),
-    WordBuffer::from_u8s([b'm', b'a', b'n', b'l', b'y']),
-    WordBuffer::from_u8s([b'm', b'a', b'n', b'o', b'r']),
-    WordBuffer::from_u8s([b'm', b'a', b'p', b'l', b'e']),
-    WordBuffer::from_u8s([b'm', b'a', b'r', b'c', b'h']),
-    WordBuffer::from_u8s([b'm', b'a', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'm', b'a', b'r', b's', b'h']),
-    WordBuffer::from_u8s([b'm', b'a', b's', b'o', b'n']),
-    WordBuffer::from_u8s([b'm', b'a', b's', b's', b'e']),
-    WordBuffer::from_u8s([b'm', b'a', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'm', b'a', b't', b'e', b'y']),
-    WordBuffer::from_u8s([b'm', b'a', b'u', b'v', b'e']),
-    WordBuffer::from_u8s([b'm', b'a', b'x', b'i', b'm']),
-    WordBuffer::from_u8s([b'm', b'a', b'y', b'b', b'e']),
-    WordBuffer::from_u8s([b'm', b'a', b'y', b'o', b'r']),
-    WordBuffer::from_u8s([b'm', b'e', b'a', b'l', b'y']),
-    WordBuffer::from_u8s([b'm', b'e', b'a', b'n', b't']),
-    WordBuffer::from_u8s([b'm', b'e', b'a', b't', b'y']),
-    WordBuffer::from_u8s([b'm', b'e', b'c', b'c', b'a']),
-    WordBuffer::from_u8s([b'm', b'e', b'd', b'a', b'l']),
-    WordBuffer::from_u8s([b'm', b'e', b'd', b'i', b'a']),
-    WordBuffer::from_u8s([b'm', b'e', b'd', b'i', b'c']),
-    WordBuffer::from_u8s([b'm', b'e', b'l', b'e', b'e']),
-    WordBuffer::from_u8s([b'm', b'e', b'l', b'o', b'n']),
-    WordBuffer::from_u8s([b'm', b'e', b'r', b'c', b'y']),
-    WordBuffer::from_u8s([b'm', b'e', b'r', b'g', b'e']),
-    WordBuffer::from_u8s([b'm', b'e', b'r', b'i', b't']),
-    WordBuffer::from_u8s([b'm', b'e', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'm', b'e', b't', b'a', b'l']),
-    WordBuffer::from_u8s([b'm', b'e', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'm', b'e', b't', b'r', b'o']),
-    WordBuffer::from_u8s([b'm', b'i', b'c', b'r', b'o']),
-    WordBuffer::from_u8s([b'm', b'i', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'm', b'i', b'd', b's', b't']),
-    WordBuffer::from_u8s([b'm', b'i', b'g', b'h', b't']),
-    WordBuffer::from_u8s([b'm', b'i', b'l', b'k', b'y']),
-    WordBuffer::from_u8s([b'm', b'i', b'm', b'i', b'c']),
-    WordBuffer::from_u8s([b'm', b'i', b'n', b'c', b'e']),
-    WordBuffer::from_u8s([b'm', b'i', b'n', b'e', b'r']),
-    WordBuffer::from_u8s([b'm', b'i', b'n', b'i', b'm']),
-    WordBuffer::from_u8s([b'm', b'i', b'n', b'o', b'r']),
-    WordBuffer::from_u8s([b'm', b'i', b'n', b't', b'y']),
-    WordBuffer::from_u8s([b'm', b'i', b'n', b'u', b's']),
-    WordBuffer::from_u8s([b'm', b'i', b'r', b't', b'h']),
-    WordBuffer::from_u8s([b'm', b'i', b's', b'e', b'r']),
-    WordBuffer::from_u8s([b'm', b'i', b's', b's', b'y']),
-    WordBuffer::from_u8s([b'm', b'o', b'c', b'h', b'a']),
-    WordBuffer::from_u8s([b'm', b'o', b'd', b'a', b'l']),
-    WordBuffer::from_u8s([b'm', b'o', b'd', b'e', b'l']),
-    WordBuffer::from_u8s([b'm', b'o', b'd', b'e', b'm']),
-    WordBuffer::from_u8s([b'm', b'o', b'g', b'u', b'l']),
-    WordBuffer::from_u8s([b'm', b'o', b'i', b's', b't']),
-    WordBuffer::from_u8s([b'm', b'o', b'l', b'a', b'r']),
-    WordBuffer::from_u8s([b'm', b'o', b'l', b'd', b'y']),
-    WordBuffer::from_u8s([b'm', b'o', b'n', b'e', b'y']),
-    WordBuffer::from_u8s([b'm', b'o', b'n', b't', b'h']),
-    WordBuffer::from_u8s([b'm', b'o', b'o', b'd', b'y']),
-    WordBuffer::from_u8s([b'm', b'o', b'o', b's', b'e']),
-    WordBuffer::from_u8s([b'm', b'o', b'r', b'a', b'l']),
-    WordBuffer::from_u8s([b'm', b'o', b'r', b'o', b'n']),
-    WordBuffer::from_u8s([b'm', b'o', b'r', b'p', b'h']),
-    WordBuffer::from_u8s([b'm', b'o', b's', b's', b'y']),
-    WordBuffer::from_u8s([b'm', b'o', b't', b'e', b'l']),
-    WordBuffer::from_u8s([b'm', b'o', b't', b'i', b'f']),
-    WordBuffer::from_u8s([b'm', b'o', b't', b'o', b'r']),
-    WordBuffer::from_u8s([b'm', b'o', b't', b't', b'o']),
-    WordBuffer::from_u8s([b'm', b'o', b'u', b'l', b't']),
-    WordBuffer::from_u8s([b'm', b'o', b'u', b'n', b'd']),
-    WordBuffer::from_u8s([b'm', b'o', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b'm', b'o', b'u', b'r', b'n']),
-    WordBuffer::from_u8s([b'm', b'o', b'u', b's', b'e']),
-    WordBuffer::from_u8s([b'm', b'o', b'u', b't', b'h']),
-    WordBuffer::from_u8s([b'm', b'o', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'm', b'o', b'v', b'i', b'e']),
-    WordBuffer::from_u8s([b'm', b'o', b'w', b'e', b'r']),
-    WordBuffer::from_u8s([b'm', b'u', b'c', b'k', b'y']),
-    WordBuffer::from_u8s([b'm', b'u', b'c', b'u', b's']),
-    WordBuffer::from_u8s([b'm', b'u', b'd', b'd', b'y']),
-    WordBuffer::from_u8s([b'm', b'u', b'l', b'c', b'h']),
-    WordBuffer::from_u8s([b'm', b'u', b'm', b'm', b'y']),
-    WordBuffer::from_u8s([b'm', b'u', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'm', b'u', b'r', b'a', b'l']),
-    WordBuffer::from_u8s([b'm', b'u', b'r', b'k', b'y']),
-    WordBuffer::from_u8s([b'm', b'u', b's', b'h', b'y']),
-    WordBuffer::from_u8s([b'm', b'u', b's', b'i', b'c']),
-    WordBuffer::from_u8s([b'm', b'u', b's', b'k', b'y']),
-    WordBuffer::from_u8s([b'm', b'u', b's', b't', b'y']),
-    WordBuffer::from_u8s([b'm', b'y', b'r', b'r', b'h']),
-    WordBuffer::from_u8s([b'n', b'a', b'd', b'i', b'r']),
-    WordBuffer::from_u8s([b'n', b'a', b'i', b'v', b'e']),
-    WordBuffer::from_u8s([b'n', b'a', b'n', b'n', b'y']),
-    WordBuffer::from_u8s([b'n', b'a', b's', b'a', b'l']),
-    WordBuffer::from_u8s([b'n', b'a', b's', b't', b'y']),
-    WordBuffer::from_u8s([b'n', b'a', b't', b'a', b'l']),
-    WordBuffer::from_u8s([b'n', b'a', b'v', b'a', b'l']),
-    WordBuffer::from_u8s([b'n', b'a', b'v', b'e', b'l']),
-    WordBuffer::from_u8s([b'n', b'e', b'e', b'd', b'y']),
-    WordBuffer::from_u8s([b'n', b'e', b'i', b'g', b'h']),
-    WordBuffer::from_u8s([b'n', b'e', b'r', b'd', b'y']),
-    WordBuffer::from_u8s([b'n', b'e', b'r', b'v', b'e']),
-    WordBuffer::from_u8s([b'n', b'e', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'n', b'e', b'w', b'e', b'r']),
-    WordBuffer::from_u8s([b'n', b'e', b'w', b'l', b'y']),
-    WordBuffer::from_u8s([b'n', b'i', b'c', b'e', b'r']),
-    WordBuffer::from_u8s([b'n', b'i', b'c', b'h', b'e']),
-    WordBuffer::from_u8s([b'n', b'i', b'e', b'c', b'e']),
-    WordBuffer::from_u8s([b'n', b'i', b'g', b'h', b't']),
-    WordBuffer::from_u8s([b'n', b'i', b'n', b'j', b'a']),
-    WordBuffer::from_u8s([b'n', b'i', b'n', b'n', b'y']),
-    WordBuffer::from_u8s([b'n', b'i', b'n', b't', b'h']),
-    WordBuffer::from_u8s([b'n', b'o', b'b', b'l', b'e']),
-    WordBuffer::from_u8s([b'n', b'o', b'b', b'l', b'y']),
-    WordBuffer::from_u8s([b'n', b'o', b'i', b's', b'e']),
-    WordBuffer::from_u8s([b'n', b'o', b'i', b's', b'y']),
-    WordBuffer::from_u8s([b'n', b'o', b'm', b'a', b'd']),
-    WordBuffer::from_u8s([b'n', b'o', b'o', b's', b'e']),
-    WordBuffer::from_u8s([b'n', b'o', b'r', b't', b'h']),
-    WordBuffer::from_u8s([b'n', b'o', b's', b'e', b'y']),
-    WordBuffer::from_u8s([b'n', b'o', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'n', b'o', b'v', b'e', b'l']),
-    WordBuffer::from_u8s([b'n', b'u', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'n', b'u', b'r', b's', b'e']),
-    WordBuffer::from_u8s([b'n', b'u', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'n', b'y', b'l', b'o', b'n']),
-    WordBuffer::from_u8s([b'n', b'y', b'm', b'p', b'h']),
-    WordBuffer::from_u8s([b'o', b'a', b'k', b'e', b'n']),
-    WordBuffer::from_u8s([b'o', b'b', b'e', b's', b'e']),
-    WordBuffer::from_u8s([b'o', b'c', b'c', b'u', b'r']),
-    WordBuffer::from_u8s([b'o', b'c', b'e', b'a', b'n']),
-    WordBuffer::from_u8s([b'o', b'c', b't', b'a', b'l']),
-    WordBuffer::from_u8s([b'o', b'c', b't', b'e', b't']),
-    WordBuffer::from_u8s([b'o', b'd', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'o', b'd', b'd', b'l', b'y']),
-    WordBuffer::from_u8s([b'o', b'f', b'f', b'a', b'l']),
-    WordBuffer::from_u8s([b'o', b'f', b'f', b'e', b'r']),
-    WordBuffer::from_u8s([b'o', b'f', b't', b'e', b'n']),
-    WordBuffer::from_u8s([b'o', b'l', b'd', b'e', b'n']),
-    WordBuffer::from_u8s([b'o', b'l', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'o', b'l', b'i', b'v', b'e']),
-    WordBuffer::from_u8s([b'o', b'm', b'b', b'r', b'e']),
-    WordBuffer::from_u8s([b'o', b'm', b'e', b'g', b'a']),
-    WordBuffer::from_u8s([b'o', b'n', b'i', b'o', b'n']),
-    WordBuffer::from_u8s([b'o', b'n', b's', b'e', b't']),
-    WordBuffer::from_u8s([b'o', b'p', b'e', b'r', b'a']),
-    WordBuffer::from_u8s([b'o', b'p', b'i', b'n', b'e']),
-    WordBuffer::from_u8s([b'o', b'p', b'i', b'u', b'm']),
-    WordBuffer::from_u8s([b'o', b'p', b't', b'i', b'c']),
-    WordBuffer::from_u8s([b'o', b'r', b'b', b'i', b't']),
-    WordBuffer::from_u8s([b'o', b'r', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'o', b'r', b'g', b'a', b'n']),
-    WordBuffer::from_u8s([b'o', b't', b'h', b'e', b'r']),
-    WordBuffer::from_u8s([b'o', b't', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'o', b'u', b'g', b'h', b't']),
-    WordBuffer::from_u8s([b'o', b'u', b'n', b'c', b'e']),
-    WordBuffer::from_u8s([b'o', b'u', b't', b'd', b'o']),
-    WordBuffer::from_u8s([b'o', b'u', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'o', b'u', b't', b'g', b'o']),
-    WordBuffer::from_u8s([b'o', b'v', b'a', b'r', b'y']),
-    WordBuffer::from_u8s([b'o', b'v', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b'o', b'v', b'e', b'r', b't']),
-    WordBuffer::from_u8s([b'o', b'v', b'i', b'n', b'e']),
-    WordBuffer::from_u8s([b'o', b'v', b'o', b'i', b'd']),
-    WordBuffer::from_u8s([b'o', b'w', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'o', b'w', b'n', b'e', b'r']),
-    WordBuffer::from_u8s([b'o', b'x', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b'o', b'z', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b'p', b'a', b'd', b'd', b'y']),
-    WordBuffer::from_u8s([b'p', b'a', b'g', b'a', b'n']),
-    WordBuffer::from_u8s([b'p', b'a', b'i', b'n', b't']),
-    WordBuffer::from_u8s([b'p', b'a', b'l', b'e', b'r']),
-    WordBuffer::from_u8s([b'p', b'a', b'l', b's', b'y']),
-    WordBuffer::from_u8s([b'p', b'a', b'n', b'e', b'l']),
-    WordBuffer::from_u8s([b'p', b'a', b'n', b'i', b'c']),
-    WordBuffer::from_u8s([b'p', b'a', b'n', b's', b'y']),
-    WordBuffer::from_u8s([b'p', b'a', b'p', b'a', b'l']),
-    WordBuffer::from_u8s([b'p', b'a', b'p', b'e', b'r']),
-    WordBuffer::from_u8s([b'p', b'a', b'r', b'e', b'r']),
-    WordBuffer::from_u8s([b'p', b'a', b'r', b'k', b'a']),
-    WordBuffer::from_u8s([b'p', b'a', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'p', b'a', b'r', b's', b'e']),
-    WordBuffer::from_u8s([b'p', b'a', b'r', b't', b'y']),
-    WordBuffer::from_u8s([b'p', b'a', b's', b't', b'a']),
-    WordBuffer::from_u8s([b'p', b'a', b's', b't', b'e']),
-    WordBuffer::from_u8s([b'p', b'a', b's', b't', b'y']),
-    WordBuffer::from_u8s([b'p', b'a', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'p', b'a', b't', b'i', b'o']),
-    WordBuffer::from_u8s([b'p', b'a', b't', b's', b'y']),
-    WordBuffer::from_u8s([b'p', b'a', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'p', b'a', b'u', b's', b'e']),
-    WordBuffer::from_u8s([b'p', b'a', b'y', b'e', b'e']),
-    WordBuffer::from_u8s([b'p', b'a', b'y', b'e', b'r']),
-    WordBuffer::from_u8s([b'p', b'e', b'a', b'c', b'e']),
-    WordBuffer::from_u8s([b'p', b'e', b'a', b'c', b'h']),
-    WordBuffer::from_u8s([b'p', b'e', b'a', b'r', b'l']),
-    WordBuffer::from_u8s([b'p', b'e', b'c', b'a', b'n']),
-    WordBuffer::from_u8s([b'p', b'e', b'd', b'a', b'l']),
-    WordBuffer::from_u8s([b'p', b'e', b'n', b'a', b'l']),
-    WordBuffer::from_u8s([b'p', b'e', b'n', b'c', b'e']),
-    WordBuffer::from_u8s([b'p', b'e', b'n', b'n', b'e']),
-    WordBuffer::from_u8s([b'p', b'e', b'n', b'n', b'y']),
-    WordBuffer::from_u8s([b'p', b'e', b'r', b'c', b'h']),
-    WordBuffer::from_u8s([b'p', b'e', b'r', b'i', b'l']),
-    WordBuffer::from_u8s([b'p', b'e', b'r', b'k', b'y']),
-    WordBuffer::from_u8s([b'p', b'e', b's', b'k', b'y']),
-    WordBuffer::from_u8s([b'p', b'e', b's', b't', b'o']),
-    WordBuffer::from_u8s([b'p', b'e', b't', b'a', b'l']),
-    WordBuffer::from_u8s([b'p', b'e', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'p', b'h', b'a', b's', b'e']),
-    WordBuffer::from_u8s([b'p', b'h', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b'p', b'h', b'o', b'n', b'y']),
-    WordBuffer::from_u8s([b'p', b'h', b'o', b't', b'o']),
-    WordBuffer::from_u8s([b'p', b'i', b'a', b'n', b'o']),
-    WordBuffer::from_u8s([b'p', b'i', b'c', b'k', b'y']),
-    WordBuffer::from_u8s([b'p', b'i', b'e', b'c', b'e']),
-    WordBuffer::from_u8s([b'p', b'i', b'e', b't', b'y']),
-    WordBuffer::from_u8s([b'p', b'i', b'g', b'g', b'y']),
-    WordBuffer::from_u8s([b'p', b'i', b'l', b'o', b't']),
-    WordBuffer::from_u8s([b'p', b'i', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'p', b'i', b'n', b'e', b'y']),
-    WordBuffer::from_u8s([b'p', b'i', b'n', b'k', b'y']),
-    WordBuffer::from_u8s([b'p', b'i', b'n', b't', b'o']),
-    WordBuffer::from_u8s([b'p', b'i', b'p', b'e', b'r']),
-    WordBuffer::from_u8s([b'p', b'i', b'q', b'u', b'e']),
-    WordBuffer::from_u8s([b'p', b'i', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'p', b'i', b't', b'h', b'y']),
-    WordBuffer::from_u8s([b'p', b'i', b'v', b'o', b't']),
-    WordBuffer::from_u8s([b'p', b'i', b'x', b'e', b'l']),
-    WordBuffer::from_u8s([b'p', b'i', b'x', b'i', b'e']),
-    WordBuffer::from_u8s([b'p', b'i', b'z', b'z', b'a']),
-    WordBuffer::from_u8s([b'p', b'l', b'a', b'c', b'e']),
-    WordBuffer::from_u8s([b'p', b'l', b'a', b'i', b'd']),
-    WordBuffer::from_u8s([b'p', b'l', b'a', b'i', b'n']),
-    WordBuffer::from_u8s([b'p', b'l', b'a', b'i', b't']),
-    WordBuffer::from_u8s([b'p', b'l', b'a', b'n', b'e']),
-    WordBuffer::from_u8s([b'p', b'l', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b'p', b'l', b'a', b'n', b't']),
-    WordBuffer::from_u8s([b'p', b'l', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b'p', b'l', b'a', b'z', b'a']),
-    WordBuffer::from_u8s([b'p', b'l', b'e', b'a', b'd']),
-    WordBuffer::from_u8s([b'p', b'l', b'e', b'a', b't']),
-    WordBuffer::from_u8s([b'p', b'l', b'i', b'e', b'd']),
-    WordBuffer::from_u8s([b'p', b'l', b'i', b'e', b'r']),
-    WordBuffer::from_u8s([b'p', b'l', b'u', b'c', b'k']),
-    WordBuffer::from_u8s([b'p', b'l', b'u', b'm', b'b']),
-    WordBuffer::from_u8s([b'p', b'l', b'u', b'm', b'e']),
-    WordBuffer::from_u8s([b'p', b'l', b'u', b'm', b'p']),
-    WordBuffer::from_u8s([b'p', b'l', b'u', b'n', b'k']),
-    WordBuffer::from_u8s([b'p', b'l', b'u', b's', b'h']),
-    WordBuffer::from_u8s([b'p', b'o', b'e', b's', b'y']),
-    WordBuffer::from_u8s([b'p', b'o', b'i', b'n', b't']),
-    WordBuffer::from_u8s([b'p', b'o', b'i', b's', b'e']),
-    WordBuffer::from_u8s([b'p', b'o', b'k', b'e', b'r']),
-    WordBuffer::from_u8s([b'p', b'o', b'l', b'a', b'r']),
-    WordBuffer::from_u8s([b'p', b'o', b'l', b'k', b'a']),
-    WordBuffer::from_u8s([b'p', b'o', b'l', b'y', b'p']),
-    WordBuffer::from_u8s([b'p', b'o', b'o', b'c', b'h']),
-    WordBuffer::from_u8s([b'p', b'o', b'p', b'p', b'y']),
-    WordBuffer::from_u8s([b'p', b'o', b'r', b'c', b'h']),
-    WordBuffer::from_u8s([b'p', b'o', b's', b'e', b'r']),
-    WordBuffer::from_u8s([b'p', b'o', b's', b'i', b't']),
-    WordBuffer::from_u8s([b'p', b'o', b's', b's', b'e']),
-    WordBuffer::from_u8s([b'p', b'o', b'u', b'c', b'h']),
-    WordBuffer::from_u8s([b'p', b'o', b'u', b'n', b'd']),
-    WordBuffer::from_u8s([b'p', b'o', b'u', b't', b'y']),
-    WordBuffer::from_u8s([b'p', b'o', b'w', b'e', b'r']),
-    WordBuffer::from_u8s([b'p', b'r', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b'p', b'r', b'a', b'w', b'n']),
-    WordBuffer::from_u8s([b'p', b'r', b'e', b'e', b'n']),
-    WordBuffer::from_u8s([b'p', b'r', b'e', b's', b's']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b'c', b'e']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b'e', b'd']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b'm', b'e']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b'm', b'o']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b'n', b't']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b'o', b'r']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b's', b'm']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b'v', b'y']),
-    WordBuffer::from_u8s([b'p', b'r', b'i', b'z', b'e']),
-    WordBuffer::from_u8s([b'p', b'r', b'o', b'b', b'e']),
-    WordBuffer::from_u8s([b'p', b'r', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b'p', b'r', b'o', b'n', b'g']),
-    WordBuffer::from_u8s([b'p', b'r', b'o', b'o', b'f']),
-    WordBuffer::from_u8s([b'p', b'r', b'o', b's', b'e']),
-    WordBuffer::from_u8s([b'p', b'r', b'o', b'u', b'd']),
-    WordBuffer::from_u8s([b'p', b'r', b'o', b'v', b'e']),
-    WordBuffer::from_u8s([b'p', b'r', b'o', b'w', b'l']),
-    WordBuffer::from_u8s([b'p', b'r', b'o', b'x', b'y']),
-    WordBuffer::from_u8s([b'p', b'r', b'u', b'd', b'e']),
-    WordBuffer::from_u8s([b'p', b'r', b'u', b'n', b'e']),
-    WordBuffer::from_u8s([b'p', b's', b'a', b'l', b'm']),
-    WordBuffer::from_u8s([b'p', b'u', b'b', b'i', b'c']),
-    WordBuffer::from_u8s([b'p', b'u', b'd', b'g', b'y']),
-    WordBuffer::from_u8s([b'p', b'u', b'f', b'f', b'y']),
-    WordBuffer::from_u8s([b'p', b'u', b'l', b'p', b'y']),
-    WordBuffer::from_u8s([b'p', b'u', b'l', b's', b'e']),
-    WordBuffer::from_u8s([b'p', b'u', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'p', b'u', b'p', b'a', b'l']),
-    WordBuffer::from_u8s([b'p', b'u', b'p', b'i', b'l']),
-    WordBuffer::from_u8s([b'p', b'u', b'p', b'p', b'y']),
-    WordBuffer::from_u8s([b'p', b'u', b'r', b'e', b'e']),
-    WordBuffer::from_u8s([b'p', b'u', b'r', b'e', b'r']),
-    WordBuffer::from_u8s([b'p', b'u', b'r', b'g', b'e']),
-    WordBuffer::from_u8s([b'p', b'u', b'r', b's', b'e']),
-    WordBuffer::from_u8s([b'p', b'u', b's', b'h', b'y']),
-    WordBuffer::from_u8s([b'p', b'u', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'p', b'y', b'g', b'm', b'y']),
-    WordBuffer::from_u8s([b'q', b'u', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b'q', b'u', b'a', b'i', b'l']),
-    WordBuffer::from_u8s([b'q', b'u', b'a', b'k', b'e']),
-    WordBuffer::from_u8s([b'q', b'u', b'a', b'l', b'm']),
-    WordBuffer::from_u8s([b'q', b'u', b'a', b'r', b'k']),
-    WordBuffer::from_u8s([b'q', b'u', b'a', b'r', b't']),
-    WordBuffer::from_u8s([b'q', b'u', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b'q', b'u', b'a', b's', b'i']),
-    WordBuffer::from_u8s([b'q', b'u', b'e', b'e', b'n']),
-    WordBuffer::from_u8s([b'q', b'u', b'e', b'e', b'r']),
-    WordBuffer::from_u8s([b'q', b'u', b'e', b'l', b'l']),
-    WordBuffer::from_u8s([b'q', b'u', b'e', b'r', b'y']),
-    WordBuffer::from_u8s([b'q', b'u', b'e', b's', b't']),
-    WordBuffer::from_u8s([b'q', b'u', b'e', b'u', b'e']),
-    WordBuffer::from_u8s([b'q', b'u', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b'q', b'u', b'i', b'e', b't']),
-    WordBuffer::from_u8s([b'q', b'u', b'i', b'l', b'l']),
-    WordBuffer::from_u8s([b'q', b'u', b'i', b'l', b't']),
-    WordBuffer::from_u8s([b'q', b'u', b'i', b'r', b'k']),
-    WordBuffer::from_u8s([b'q', b'u', b'i', b't', b'e']),
-    WordBuffer::from_u8s([b'q', b'u', b'o', b't', b'a']),
-    WordBuffer::from_u8s([b'q', b'u', b'o', b't', b'e']),
-    WordBuffer::from_u8s([b'q', b'u', b'o', b't', b'h']),
-    WordBuffer::from_u8s([b'r', b'a', b'b', b'b', b'i']),
-    WordBuffer::from_u8s([b'r', b'a', b'b', b'i', b'd']),
-    WordBuffer::from_u8s([b'r', b'a', b'c', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'a', b'd', b'a', b'r']),
-    WordBuffer::from_u8s([b'r', b'a', b'd', b'i', b'i']),
-    WordBuffer::from_u8s([b'r', b'a', b'd', b'i', b'o']),
-    WordBuffer::from_u8s([b'r', b'a', b'i', b'n', b'y']),
-    WordBuffer::from_u8s([b'r', b'a', b'i', b's', b'e']),
-    WordBuffer::from_u8s([b'r', b'a', b'j', b'a', b'h']),
-    WordBuffer::from_u8s([b'r', b'a', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'r', b'a', b'l', b'p', b'h']),
-    WordBuffer::from_u8s([b'r', b'a', b'm', b'e', b'n']),
-    WordBuffer::from_u8s([b'r', b'a', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'r', b'a', b'n', b'd', b'y']),
-    WordBuffer::from_u8s([b'r', b'a', b'n', b'g', b'e']),
-    WordBuffer::from_u8s([b'r', b'a', b'p', b'i', b'd']),
-    WordBuffer::from_u8s([b'r', b'a', b'r', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'a', b's', b'p', b'y']),
-    WordBuffer::from_u8s([b'r', b'a', b't', b'i', b'o']),
-    WordBuffer::from_u8s([b'r', b'a', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'r', b'a', b'v', b'e', b'n']),
-    WordBuffer::from_u8s([b'r', b'a', b'y', b'o', b'n']),
-    WordBuffer::from_u8s([b'r', b'a', b'z', b'o', b'r']),
-    WordBuffer::from_u8s([b'r', b'e', b'a', b'c', b'h']),
-    WordBuffer::from_u8s([b'r', b'e', b'a', b'c', b't']),
-    WordBuffer::from_u8s([b'r', b'e', b'a', b'd', b'y']),
-    WordBuffer::from_u8s([b'r', b'e', b'a', b'l', b'm']),
-    WordBuffer::from_u8s([b'r', b'e', b'a', b'r', b'm']),
-    WordBuffer::from_u8s([b'r', b'e', b'b', b'a', b'r']),
-    WordBuffer::from_u8s([b'r', b'e', b'b', b'e', b'l']),
-    WordBuffer::from_u8s([b'r', b'e', b'b', b'u', b's']),
-    WordBuffer::from_u8s([b'r', b'e', b'b', b'u', b't']),
-    WordBuffer::from_u8s([b'r', b'e', b'c', b'a', b'p']),
-    WordBuffer::from_u8s([b'r', b'e', b'c', b'u', b'r']),
-    WordBuffer::from_u8s([b'r', b'e', b'c', b'u', b't']),
-    WordBuffer::from_u8s([b'r', b'e', b'e', b'd', b'y']),
-    WordBuffer::from_u8s([b'r', b'e', b'f', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'e', b'f', b'i', b't']),
-    WordBuffer::from_u8s([b'r', b'e', b'g', b'a', b'l']),
-    WordBuffer::from_u8s([b'r', b'e', b'h', b'a', b'b']),
-    WordBuffer::from_u8s([b'r', b'e', b'i', b'g', b'n']),
-    WordBuffer::from_u8s([b'r', b'e', b'l', b'a', b'x']),
-    WordBuffer::from_u8s([b'r', b'e', b'l', b'a', b'y']),
-    WordBuffer::from_u8s([b'r', b'e', b'l', b'i', b'c']),
-    WordBuffer::from_u8s([b'r', b'e', b'm', b'i', b't']),
-    WordBuffer::from_u8s([b'r', b'e', b'n', b'a', b'l']),
-    WordBuffer::from_u8s([b'r', b'e', b'n', b'e', b'w']),
-    WordBuffer::from_u8s([b'r', b'e', b'p', b'a', b'y']),
-    WordBuffer::from_u8s([b'r', b'e', b'p', b'e', b'l']),
-    WordBuffer::from_u8s([b'r', b'e', b'p', b'l', b'y']),
-    WordBuffer::from_u8s([b'r', b'e', b'r', b'u', b'n']),
-    WordBuffer::from_u8s([b'r', b'e', b's', b'e', b't']),
-    WordBuffer::from_u8s([b'r', b'e', b's', b'i', b'n']),
-    WordBuffer::from_u8s([b'r', b'e', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'r', b'e', b't', b'r', b'o']),
-    WordBuffer::from_u8s([b'r', b'e', b't', b'r', b'y']),
-    WordBuffer::from_u8s([b'r', b'e', b'u', b's', b'e']),
-    WordBuffer::from_u8s([b'r', b'e', b'v', b'e', b'l']),
-    WordBuffer::from_u8s([b'r', b'e', b'v', b'u', b'e']),
-    WordBuffer::from_u8s([b'r', b'h', b'i', b'n', b'o']),
-    WordBuffer::from_u8s([b'r', b'h', b'y', b'm', b'e']),
-    WordBuffer::from_u8s([b'r', b'i', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'i', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'r', b'i', b'f', b'l', b'e']),
-    WordBuffer::from_u8s([b'r', b'i', b'g', b'h', b't']),
-    WordBuffer::from_u8s([b'r', b'i', b'g', b'i', b'd']),
-    WordBuffer::from_u8s([b'r', b'i', b'g', b'o', b'r']),
-    WordBuffer::from_u8s([b'r', b'i', b'n', b's', b'e']),
-    WordBuffer::from_u8s([b'r', b'i', b'p', b'e', b'n']),
-    WordBuffer::from_u8s([b'r', b'i', b'p', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'i', b's', b'e', b'n']),
-    WordBuffer::from_u8s([b'r', b'i', b's', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'i', b's', b'k', b'y']),
-    WordBuffer::from_u8s([b'r', b'i', b'v', b'a', b'l']),
-    WordBuffer::from_u8s([b'r', b'i', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'i', b'v', b'e', b't']),
-    WordBuffer::from_u8s([b'r', b'o', b'a', b'c', b'h']),
-    WordBuffer::from_u8s([b'r', b'o', b'a', b's', b't']),
-    WordBuffer::from_u8s([b'r', b'o', b'b', b'i', b'n']),
-    WordBuffer::from_u8s([b'r', b'o', b'b', b'o', b't']),
-    WordBuffer::from_u8s([b'r', b'o', b'c', b'k', b'y']),
-    WordBuffer::from_u8s([b'r', b'o', b'd', b'e', b'o']),
-    WordBuffer::from_u8s([b'r', b'o', b'g', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'o', b'g', b'u', b'e']),
-    WordBuffer::from_u8s([b'r', b'o', b'o', b'm', b'y']),
-    WordBuffer::from_u8s([b'r', b'o', b'o', b's', b't']),
-    WordBuffer::from_u8s([b'r', b'o', b't', b'o', b'r']),
-    WordBuffer::from_u8s([b'r', b'o', b'u', b'g', b'e']),
-    WordBuffer::from_u8s([b'r', b'o', b'u', b'g', b'h']),
-    WordBuffer::from_u8s([b'r', b'o', b'u', b'n', b'd']),
-    WordBuffer::from_u8s([b'r', b'o', b'u', b's', b'e']),
-    WordBuffer::from_u8s([b'r', b'o', b'u', b't', b'e']),
-    WordBuffer::from_u8s([b'r', b'o', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'o', b'w', b'd', b'y']),
-    WordBuffer::from_u8s([b'r', b'o', b'w', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'o', b'y', b'a', b'l']),
-    WordBuffer::from_u8s([b'r', b'u', b'd', b'd', b'y']),
-    WordBuffer::from_u8s([b'r', b'u', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'u', b'g', b'b', b'y']),
-    WordBuffer::from_u8s([b'r', b'u', b'l', b'e', b'r']),
-    WordBuffer::from_u8s([b'r', b'u', b'm', b'b', b'a']),
-    WordBuffer::from_u8s([b'r', b'u', b'm', b'o', b'r']),
-    WordBuffer::from_u8s([b'r', b'u', b'p', b'e', b'e']),
-    WordBuffer::from_u8s([b'r', b'u', b'r', b'a', b'l']),
-    WordBuffer::from_u8s([b'r', b'u', b's', b't', b'y']),
-    WordBuffer::from_u8s([b's', b'a', b'd', b'l', b'y']),
-    WordBuffer::from_u8s([b's', b'a', b'f', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'a', b'i', b'n', b't']),
-    WordBuffer::from_u8s([b's', b'a', b'l', b'a', b'd']),
-    WordBuffer::from_u8s([b's', b'a', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b's', b'a', b'l', b'o', b'n']),
-    WordBuffer::from_u8s([b's', b'a', b'l', b's', b'a']),
-    WordBuffer::from_u8s([b's', b'a', b'l', b't', b'y']),
-    WordBuffer::from_u8s([b's', b'a', b'l', b'v', b'e']),
-    WordBuffer::from_u8s([b's', b'a', b'l', b'v', b'o']),
-    WordBuffer::from_u8s([b's', b'a', b'n', b'd', b'y']),
-    WordBuffer::from_u8s([b's', b'a', b'n', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'a', b'p', b'p', b'y']),
-    WordBuffer::from_u8s([b's', b'a', b's', b's', b'y']),
-    WordBuffer::from_u8s([b's', b'a', b't', b'i', b'n']),
-    WordBuffer::from_u8s([b's', b'a', b't', b'y', b'r']),
-    WordBuffer::from_u8s([b's', b'a', b'u', b'c', b'e']),
-    WordBuffer::from_u8s([b's', b'a', b'u', b'c', b'y']),
-    WordBuffer::from_u8s([b's', b'a', b'u', b'n', b'a']),
-    WordBuffer::from_u8s([b's', b'a', b'u', b't', b'e']),
-    WordBuffer::from_u8s([b's', b'a', b'v', b'o', b'r']),
-    WordBuffer::from_u8s([b's', b'a', b'v', b'o', b'y']),
-    WordBuffer::from_u8s([b's', b'a', b'v', b'v', b'y']),
-    WordBuffer::from_u8s([b's', b'c', b'a', b'l', b'd']),
-    WordBuffer::from_u8s([b's', b'c', b'a', b'l', b'e']),
-    WordBuffer::from_u8s([b's', b'c', b'a', b'l', b'p']),
-    WordBuffer::from_u8s([b's', b'c', b'a', b'l', b'y']),
-    WordBuffer::from_u8s([b's', b'c', b'a', b'm', b'p']),
-    WordBuffer::from_u8s([b's', b'c', b'a', b'n', b't']),
-    WordBuffer::from_u8s([b's', b'c', b'a', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'c', b'a', b'r', b'f']),
-    WordBuffer::from_u8s([b's', b'c', b'a', b'r', b'y']),
-    WordBuffer::from_u8s([b's', b'c', b'e', b'n', b'e']),
-    WordBuffer::from_u8s([b's', b'c', b'e', b'n', b't']),
-    WordBuffer::from_u8s([b's', b'c', b'i', b'o', b'n']),
-    WordBuffer::from_u8s([b's', b'c', b'o', b'f', b'f']),
-    WordBuffer::from_u8s([b's', b'c', b'o', b'l', b'd']),
-    WordBuffer::from_u8s([b's', b'c', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b's', b'c', b'o', b'o', b'p']),
-    WordBuffer::from_u8s([b's', b'c', b'o', b'p', b'e']),
-    WordBuffer::from_u8s([b's', b'c', b'o', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'c', b'o', b'r', b'n']),
-    WordBuffer::from_u8s([b's', b'c', b'o', b'u', b'r']),
-    WordBuffer::from_u8s([b's', b'c', b'o', b'u', b't']),
-    WordBuffer::from_u8s([b's', b'c', b'o', b'w', b'l']),
-    WordBuffer::from_u8s([b's', b'c', b'r', b'a', b'm']),
-    WordBuffer::from_u8s([b's', b'c', b'r', b'a', b'p']),
-    WordBuffer::from_u8s([b's', b'c', b'r', b'e', b'e']),
-    WordBuffer::from_u8s([b's', b'c', b'r', b'e', b'w']),
-    WordBuffer::from_u8s([b's', b'c', b'r', b'u', b'b']),
-    WordBuffer::from_u8s([b's', b'c', b'r', b'u', b'm']),
-    WordBuffer::from_u8s([b's', b'c', b'u', b'b', b'a']),
-    WordBuffer::from_u8s([b's', b'e', b'd', b'a', b'n']),
-    WordBuffer::from_u8s([b's', b'e', b'e', b'd', b'y']),
-    WordBuffer::from_u8s([b's', b'e', b'g', b'u', b'e']),
-    WordBuffer::from_u8s([b's', b'e', b'i', b'z', b'e']),
-    WordBuffer::from_u8s([b's', b'e', b'm', b'e', b'n']),
-    WordBuffer::from_u8s([b's', b'e', b'n', b's', b'e']),
-    WordBuffer::from_u8s([b's', b'e', b'p', b'i', b'a']),
-    WordBuffer::from_u8s([b's', b'e', b'r', b'i', b'f']),
-    WordBuffer::from_u8s([b's', b'e', b'r', b'u', b'm']),
-    WordBuffer::from_u8s([b's', b'e', b'r', b'v', b'e']),
-    WordBuffer::from_u8s([b's', b'e', b't', b'u', b'p']),
-    WordBuffer::from_u8s([b's', b'e', b'v', b'e', b'n']),
-    WordBuffer::from_u8s([b's', b'e', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'e', b'w', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'd', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'd', b'y']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'f', b't']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'k', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'k', b'y']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'l', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'l', b't']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'm', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'p', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'r', b'd']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'r', b'k']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'r', b'p']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'a', b'w', b'l']),
-    WordBuffer::from_u8s([b's', b'h', b'e', b'a', b'r']),
-    WordBuffer::from_u8s([b's', b'h', b'e', b'e', b'n']),
-    WordBuffer::from_u8s([b's', b'h', b'e', b'e', b'p']),
-    WordBuffer::from_u8s([b's', b'h', b'e', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'h', b'e', b'e', b't']),
-    WordBuffer::from_u8s([b's', b'h', b'e', b'i', b'k']),
-    WordBuffer::from_u8s([b's', b'h', b'e', b'l', b'f']),
-    WordBuffer::from_u8s([b's', b'h', b'e', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b'h', b'i', b'e', b'd']),
-    WordBuffer::from_u8s([b's', b'h', b'i', b'f', b't']),
-    WordBuffer::from_u8s([b's', b'h', b'i', b'n', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'i', b'n', b'y']),
-    WordBuffer::from_u8s([b's', b'h', b'i', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'i', b'r', b'k']),
-    WordBuffer::from_u8s([b's', b'h', b'i', b'r', b't']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'a', b'l']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'o', b'k']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'o', b't']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'r', b'n']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'r', b't']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'u', b't']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'v', b'e']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'w', b'n']),
-    WordBuffer::from_u8s([b's', b'h', b'o', b'w', b'y']),
-    WordBuffer::from_u8s([b's', b'h', b'r', b'e', b'w']),
-    WordBuffer::from_u8s([b's', b'h', b'r', b'u', b'b']),
-    WordBuffer::from_u8s([b's', b'h', b'r', b'u', b'g']),
-    WordBuffer::from_u8s([b's', b'h', b'u', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b'h', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b's', b'h', b'u', b's', b'h']),
-    WordBuffer::from_u8s([b's', b'h', b'y', b'l', b'y']),
-    WordBuffer::from_u8s([b's', b'i', b'e', b'g', b'e']),
-    WordBuffer::from_u8s([b's', b'i', b'e', b'v', b'e']),
-    WordBuffer::from_u8s([b's', b'i', b'g', b'h', b't']),
-    WordBuffer::from_u8s([b's', b'i', b'g', b'm', b'a']),
-    WordBuffer::from_u8s([b's', b'i', b'l', b'k', b'y']),
-    WordBuffer::from_u8s([b's', b'i', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b's', b'i', b'n', b'c', b'e']),
-    WordBuffer::from_u8s([b's', b'i', b'n', b'e', b'w']),
-    WordBuffer::from_u8s([b's', b'i', b'n', b'g', b'e']),
-    WordBuffer::from_u8s([b's', b'i', b'r', b'e', b'n']),
-    WordBuffer::from_u8s([b's', b'i', b's', b's', b'y']),
-    WordBuffer::from_u8s([b's', b'i', b'x', b't', b'h']),
-    WordBuffer::from_u8s([b's', b'i', b'x', b't', b'y']),
-    WordBuffer::from_u8s([b's', b'k', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b's', b'k', b'i', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'k', b'i', b'f', b'f']),
-    WordBuffer::from_u8s([b's', b'k', b'i', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b'k', b'i', b'm', b'p']),
-    WordBuffer::from_u8s([b's', b'k', b'i', b'r', b't']),
-    WordBuffer::from_u8s([b's', b'k', b'u', b'l', b'k']),
-    WordBuffer::from_u8s([b's', b'k', b'u', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b'k', b'u', b'n', b'k']),
-    WordBuffer::from_u8s([b's', b'l', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b'l', b'a', b'i', b'n']),
-    WordBuffer::from_u8s([b's', b'l', b'a', b'n', b'g']),
-    WordBuffer::from_u8s([b's', b'l', b'a', b'n', b't']),
-    WordBuffer::from_u8s([b's', b'l', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b's', b'l', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b's', b'l', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b's', b'l', b'e', b'e', b'k']),
-    WordBuffer::from_u8s([b's', b'l', b'e', b'e', b'p']),
-    WordBuffer::from_u8s([b's', b'l', b'e', b'e', b't']),
-    WordBuffer::from_u8s([b's', b'l', b'e', b'p', b't']),
-    WordBuffer::from_u8s([b's', b'l', b'i', b'c', b'e']),
-    WordBuffer::from_u8s([b's', b'l', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b'l', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b's', b'l', b'i', b'm', b'e']),
-    WordBuffer::from_u8s([b's', b'l', b'i', b'm', b'y']),
-    WordBuffer::from_u8s([b's', b'l', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b's', b'l', b'i', b'n', b'k']),
-    WordBuffer::from_u8s([b's', b'l', b'o', b'o', b'p']),
-    WordBuffer::from_u8s([b's', b'l', b'o', b'p', b'e']),
-    WordBuffer::from_u8s([b's', b'l', b'o', b's', b'h']),
-    WordBuffer::from_u8s([b's', b'l', b'o', b't', b'h']),
-    WordBuffer::from_u8s([b's', b'l', b'u', b'm', b'p']),
-    WordBuffer::from_u8s([b's', b'l', b'u', b'n', b'g']),
-    WordBuffer::from_u8s([b's', b'l', b'u', b'n', b'k']),
-    WordBuffer::from_u8s([b's', b'l', b'u', b'r', b'p']),
-    WordBuffer::from_u8s([b's', b'l', b'u', b's', b'h']),
-    WordBuffer::from_u8s([b's', b'l', b'y', b'l', b'y']),
-    WordBuffer::from_u8s([b's', b'm', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b'm', b'a', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b'm', b'a', b'r', b't']),
-    WordBuffer::from_u8s([b's', b'm', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b's', b'm', b'e', b'a', b'r']),
-    WordBuffer::from_u8s([b's', b'm', b'e', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b'm', b'e', b'l', b't']),
-    WordBuffer::from_u8s([b's', b'm', b'i', b'l', b'e']),
-    WordBuffer::from_u8s([b's', b'm', b'i', b'r', b'k']),
-    WordBuffer::from_u8s([b's', b'm', b'i', b't', b'e']),
-    WordBuffer::from_u8s([b's', b'm', b'i', b't', b'h']),
-    WordBuffer::from_u8s([b's', b'm', b'o', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b'm', b'o', b'k', b'e']),
-    WordBuffer::from_u8s([b's', b'm', b'o', b'k', b'y']),
-    WordBuffer::from_u8s([b's', b'm', b'o', b't', b'e']),
-    WordBuffer::from_u8s([b's', b'n', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b'n', b'a', b'i', b'l']),
-    WordBuffer::from_u8s([b's', b'n', b'a', b'k', b'e']),
-    WordBuffer::from_u8s([b's', b'n', b'a', b'k', b'y']),
-    WordBuffer::from_u8s([b's', b'n', b'a', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'n', b'a', b'r', b'l']),
-    WordBuffer::from_u8s([b's', b'n', b'e', b'a', b'k']),
-    WordBuffer::from_u8s([b's', b'n', b'e', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'n', b'i', b'd', b'e']),
-    WordBuffer::from_u8s([b's', b'n', b'i', b'f', b'f']),
-    WordBuffer::from_u8s([b's', b'n', b'i', b'p', b'e']),
-    WordBuffer::from_u8s([b's', b'n', b'o', b'o', b'p']),
-    WordBuffer::from_u8s([b's', b'n', b'o', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'n', b'o', b'r', b't']),
-    WordBuffer::from_u8s([b's', b'n', b'o', b'u', b't']),
-    WordBuffer::from_u8s([b's', b'n', b'o', b'w', b'y']),
-    WordBuffer::from_u8s([b's', b'n', b'u', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b'n', b'u', b'f', b'f']),
-    WordBuffer::from_u8s([b's', b'o', b'a', b'p', b'y']),
-    WordBuffer::from_u8s([b's', b'o', b'b', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'o', b'g', b'g', b'y']),
-    WordBuffer::from_u8s([b's', b'o', b'l', b'a', b'r']),
-    WordBuffer::from_u8s([b's', b'o', b'l', b'i', b'd']),
-    WordBuffer::from_u8s([b's', b'o', b'l', b'v', b'e']),
-    WordBuffer::from_u8s([b's', b'o', b'n', b'a', b'r']),
-    WordBuffer::from_u8s([b's', b'o', b'n', b'i', b'c']),
-    WordBuffer::from_u8s([b's', b'o', b'o', b't', b'h']),
-    WordBuffer::from_u8s([b's', b'o', b'o', b't', b'y']),
-    WordBuffer::from_u8s([b's', b'o', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b's', b'o', b'u', b'n', b'd']),
-    WordBuffer::from_u8s([b's', b'o', b'u', b't', b'h']),
-    WordBuffer::from_u8s([b's', b'o', b'w', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'p', b'a', b'c', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'a', b'd', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b's', b'p', b'a', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'a', b'r', b'k']),
-    WordBuffer::from_u8s([b's', b'p', b'a', b's', b'm']),
-    WordBuffer::from_u8s([b's', b'p', b'a', b'w', b'n']),
-    WordBuffer::from_u8s([b's', b'p', b'e', b'a', b'k']),
-    WordBuffer::from_u8s([b's', b'p', b'e', b'a', b'r']),
-    WordBuffer::from_u8s([b's', b'p', b'e', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b'p', b'e', b'e', b'd']),
-    WordBuffer::from_u8s([b's', b'p', b'e', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b'p', b'e', b'l', b't']),
-    WordBuffer::from_u8s([b's', b'p', b'e', b'n', b'd']),
-    WordBuffer::from_u8s([b's', b'p', b'e', b'n', b't']),
-    WordBuffer::from_u8s([b's', b'p', b'e', b'r', b'm']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'c', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'c', b'y']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'e', b'd']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'e', b'l']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'k', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'k', b'y']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'l', b't']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'n', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'n', b'y']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'i', b't', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'l', b'a', b't']),
-    WordBuffer::from_u8s([b's', b'p', b'l', b'i', b't']),
-    WordBuffer::from_u8s([b's', b'p', b'o', b'i', b'l']),
-    WordBuffer::from_u8s([b's', b'p', b'o', b'k', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'o', b'o', b'f']),
-    WordBuffer::from_u8s([b's', b'p', b'o', b'o', b'k']),
-    WordBuffer::from_u8s([b's', b'p', b'o', b'o', b'l']),
-    WordBuffer::from_u8s([b's', b'p', b'o', b'o', b'n']),
-    WordBuffer::from_u8s([b's', b'p', b'o', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'o', b'r', b't']),
-    WordBuffer::from_u8s([b's', b'p', b'o', b'u', b't']),
-    WordBuffer::from_u8s([b's', b'p', b'r', b'a', b'y']),
-    WordBuffer::from_u8s([b's', b'p', b'r', b'e', b'e']),
-    WordBuffer::from_u8s([b's', b'p', b'r', b'i', b'g']),
-    WordBuffer::from_u8s([b's', b'p', b'u', b'n', b'k']),
-    WordBuffer::from_u8s([b's', b'p', b'u', b'r', b'n']),
-    WordBuffer::from_u8s([b's', b'p', b'u', b'r', b't']),
-    WordBuffer::from_u8s([b's', b'q', b'u', b'a', b'd']),
-    WordBuffer::from_u8s([b's', b'q', b'u', b'a', b't']),
-    WordBuffer::from_u8s([b's', b'q', b'u', b'i', b'b']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'f', b'f']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'g', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'i', b'd']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'i', b'n']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'i', b'r']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'k', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'l', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'l', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'm', b'p']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'n', b'd']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'r', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'r', b't']),
-    WordBuffer::from_u8s([b's', b't', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b's', b't', b'a', b't', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'e', b'a', b'd']),
-    WordBuffer::from_u8s([b's', b't', b'e', b'a', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'e', b'a', b'l']),
-    WordBuffer::from_u8s([b's', b't', b'e', b'a', b'm']),
-    WordBuffer::from_u8s([b's', b't', b'e', b'e', b'd']),
-    WordBuffer::from_u8s([b's', b't', b'e', b'e', b'l']),
-    WordBuffer::from_u8s([b's', b't', b'e', b'e', b'p']),
-    WordBuffer::from_u8s([b's', b't', b'e', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b't', b'e', b'i', b'n']),
-    WordBuffer::from_u8s([b's', b't', b'e', b'r', b'n']),
-    WordBuffer::from_u8s([b's', b't', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'i', b'f', b'f']),
-    WordBuffer::from_u8s([b's', b't', b'i', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b't', b'i', b'l', b't']),
-    WordBuffer::from_u8s([b's', b't', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b's', b't', b'i', b'n', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'i', b'n', b't']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'i', b'c']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'k', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'l', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'm', b'p']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'n', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'n', b'y']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'o', b'd']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'o', b'l']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'o', b'p']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'r', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'r', b'm']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'r', b'y']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'u', b't']),
-    WordBuffer::from_u8s([b's', b't', b'o', b'v', b'e']),
-    WordBuffer::from_u8s([b's', b't', b'r', b'a', b'p']),
-    WordBuffer::from_u8s([b's', b't', b'r', b'a', b'w']),
-    WordBuffer::from_u8s([b's', b't', b'r', b'a', b'y']),
-    WordBuffer::from_u8s([b's', b't', b'r', b'i', b'p']),
-    WordBuffer::from_u8s([b's', b't', b'r', b'u', b't']),
-    WordBuffer::from_u8s([b's', b't', b'u', b'c', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'u', b'd', b'y']),
-    WordBuffer::from_u8s([b's', b't', b'u', b'f', b'f']),
-    WordBuffer::from_u8s([b's', b't', b'u', b'm', b'p']),
-    WordBuffer::from_u8s([b's', b't', b'u', b'n', b'g']),
-    WordBuffer::from_u8s([b's', b't', b'u', b'n', b'k']),
-    WordBuffer::from_u8s([b's', b't', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b's', b't', b'y', b'l', b'e']),
-    WordBuffer::from_u8s([b's', b'u', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b's', b'u', b'g', b'a', b'r']),
-    WordBuffer::from_u8s([b's', b'u', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b's', b'u', b'i', b't', b'e']),
-    WordBuffer::from_u8s([b's', b'u', b'l', b'k', b'y']),
-    WordBuffer::from_u8s([b's', b'u', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b's', b'u', b'm', b'a', b'c']),
-    WordBuffer::from_u8s([b's', b'u', b'n', b'n', b'y']),
-    WordBuffer::from_u8s([b's', b'u', b'p', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'u', b'r', b'e', b'r']),
-    WordBuffer::from_u8s([b's', b'u', b'r', b'g', b'e']),
-    WordBuffer::from_u8s([b's', b'u', b'r', b'l', b'y']),
-    WordBuffer::from_u8s([b's', b'u', b's', b'h', b'i']),
-    WordBuffer::from_u8s([b's', b'w', b'a', b'm', b'i']),
-    WordBuffer::from_u8s([b's', b'w', b'a', b'm', b'p']),
-    WordBuffer::from_u8s([b's', b'w', b'a', b'r', b'm']),
-    WordBuffer::from_u8s([b's', b'w', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b's', b'w', b'a', b't', b'h']),
-    WordBuffer::from_u8s([b's', b'w', b'e', b'a', b'r']),
-    WordBuffer::from_u8s([b's', b'w', b'e', b'a', b't']),
-    WordBuffer::from_u8s([b's', b'w', b'e', b'e', b'p']),
-    WordBuffer::from_u8s([b's', b'w', b'e', b'e', b't']),
-    WordBuffer::from_u8s([b's', b'w', b'e', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b'w', b'e', b'p', b't']),
-    WordBuffer::from_u8s([b's', b'w', b'i', b'f', b't']),
-    WordBuffer::from_u8s([b's', b'w', b'i', b'l', b'l']),
-    WordBuffer::from_u8s([b's', b'w', b'i', b'n', b'e']),
-    WordBuffer::from_u8s([b's', b'w', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b's', b'w', b'i', b'r', b'l']),
-    WordBuffer::from_u8s([b's', b'w', b'i', b's', b'h']),
-    WordBuffer::from_u8s([b's', b'w', b'o', b'o', b'n']),
-    WordBuffer::from_u8s([b's', b'w', b'o', b'o', b'p']),
-    WordBuffer::from_u8s([b's', b'w', b'o', b'r', b'd']),
-    WordBuffer::from_u8s([b's', b'w', b'o', b'r', b'e']),
-    WordBuffer::from_u8s([b's', b'w', b'o', b'r', b'n']),
-    WordBuffer::from_u8s([b's', b'w', b'u', b'n', b'g']),
-    WordBuffer::from_u8s([b's', b'y', b'n', b'o', b'd']),
-    WordBuffer::from_u8s([b's', b'y', b'r', b'u', b'p']),
-    WordBuffer::from_u8s([b't', b'a', b'b', b'b', b'y']),
-    WordBuffer::from_u8s([b't', b'a', b'b', b'l', b'e']),
-    WordBuffer::from_u8s([b't', b'a', b'b', b'o', b'o']),
-    WordBuffer::from_u8s([b't', b'a', b'c', b'i', b't']),
-    WordBuffer::from_u8s([b't', b'a', b'c', b'k', b'y']),
-    WordBuffer::from_u8s([b't', b'a', b'f', b'f', b'y']),
-    WordBuffer::from_u8s([b't', b'a', b'i', b'n', b't']),
-    WordBuffer::from_u8s([b't', b'a', b'k', b'e', b'n']),
-    WordBuffer::from_u8s([b't', b'a', b'k', b'e', b'r']),
-    WordBuffer::from_u8s([b't', b'a', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b't', b'a', b'l', b'o', b'n']),
-    WordBuffer::from_u8s([b't', b'a', b'm', b'e', b'r']),
-    WordBuffer::from_u8s([b't', b'a', b'n', b'g', b'o']),
-    WordBuffer::from_u8s([b't', b'a', b'n', b'g', b'y']),
-    WordBuffer::from_u8s([b't', b'a', b'p', b'e', b'r']),
-    WordBuffer::from_u8s([b't', b'a', b'p', b'i', b'r']),
-    WordBuffer::from_u8s([b't', b'a', b'r', b'd', b'y']),
-    WordBuffer::from_u8s([b't', b'a', b'r', b'o', b't']),
-    WordBuffer::from_u8s([b't', b'a', b's', b't', b'e']),
-    WordBuffer::from_u8s([b't', b'a', b's', b't', b'y']),
-    WordBuffer::from_u8s([b't', b'a', b't', b't', b'y']),
-    WordBuffer::from_u8s([b't', b'a', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b't', b'a', b'w', b'n', b'y']),
-    WordBuffer::from_u8s([b't', b'e', b'a', b'c', b'h']),
-    WordBuffer::from_u8s([b't', b'e', b'a', b'r', b'y']),
-    WordBuffer::from_u8s([b't', b'e', b'a', b's', b'e']),
-    WordBuffer::from_u8s([b't', b'e', b'd', b'd', b'y']),
-    WordBuffer::from_u8s([b't', b'e', b'e', b't', b'h']),
-    WordBuffer::from_u8s([b't', b'e', b'm', b'p', b'o']),
-    WordBuffer::from_u8s([b't', b'e', b'n', b'e', b't']),
-    WordBuffer::from_u8s([b't', b'e', b'n', b'o', b'r']),
-    WordBuffer::from_u8s([b't', b'e', b'n', b's', b'e']),
-    WordBuffer::from_u8s([b't', b'e', b'n', b't', b'h']),
-    WordBuffer::from_u8s([b't', b'e', b'p', b'e', b'e']),
-    WordBuffer::from_u8s([b't', b'e', b'p', b'i', b'd']),
-    WordBuffer::from_u8s([b't', b'e', b'r', b'r', b'a']),
-    WordBuffer::from_u8s([b't', b'e', b'r', b's', b'e']),
-    WordBuffer::from_u8s([b't', b'e', b's', b't', b'y']),
-    WordBuffer::from_u8s([b't', b'h', b'a', b'n', b'k']),
-    WordBuffer::from_u8s([b't', b'h', b'e', b'f', b't']),
-    WordBuffer::from_u8s([b't', b'h', b'e', b'i', b'r']),
-    WordBuffer::from_u8s([b't', b'h', b'e', b'm', b'e']),
-    WordBuffer::from_u8s([b't', b'h', b'e', b'r', b'e']),
-    WordBuffer::from_u8s([b't', b'h', b'e', b's', b'e']),
-    WordBuffer::from_u8s([b't', b'h', b'e', b't', b'a']),
-    WordBuffer::from_u8s([b't', b'h', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b't', b'h', b'i', b'e', b'f']),
-    WordBuffer::from_u8s([b't', b'h', b'i', b'g', b'h']),
-    WordBuffer::from_u8s([b't', b'h', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b't', b'h', b'i', b'n', b'k']),
-    WordBuffer::from_u8s([b't', b'h', b'i', b'r', b'd']),
-    WordBuffer::from_u8s([b't', b'h', b'o', b'n', b'g']),
-    WordBuffer::from_u8s([b't', b'h', b'o', b'r', b'n']),
-    WordBuffer::from_u8s([b't', b'h', b'o', b's', b'e']),
-    WordBuffer::from_u8s([b't', b'h', b'r', b'e', b'e']),
-    WordBuffer::from_u8s([b't', b'h', b'r', b'e', b'w']),
-    WordBuffer::from_u8s([b't', b'h', b'r', b'o', b'b']),
-    WordBuffer::from_u8s([b't', b'h', b'r', b'o', b'w']),
-    WordBuffer::from_u8s([b't', b'h', b'r', b'u', b'm']),
-    WordBuffer::from_u8s([b't', b'h', b'u', b'm', b'b']),
-    WordBuffer::from_u8s([b't', b'h', b'u', b'm', b'p']),
-    WordBuffer::from_u8s([b't', b'h', b'y', b'm', b'e']),
-    WordBuffer::from_u8s([b't', b'i', b'a', b'r', b'a']),
-    WordBuffer::from_u8s([b't', b'i', b'b', b'i', b'a']),
-    WordBuffer::from_u8s([b't', b'i', b'd', b'a', b'l']),
-    WordBuffer::from_u8s([b't', b'i', b'g', b'e', b'r']),
-    WordBuffer::from_u8s([b't', b'i', b'g', b'h', b't']),
-    WordBuffer::from_u8s([b't', b'i', b'l', b'd', b'e']),
-    WordBuffer::from_u8s([b't', b'i', b'm', b'e', b'r']),
-    WordBuffer::from_u8s([b't', b'i', b'm', b'i', b'd']),
-    WordBuffer::from_u8s([b't', b'i', b'p', b's', b'y']),
-    WordBuffer::from_u8s([b't', b'i', b't', b'a', b'n']),
-    WordBuffer::from_u8s([b't', b'i', b't', b'h', b'e']),
-    WordBuffer::from_u8s([b't', b'i', b't', b'l', b'e']),
-    WordBuffer::from_u8s([b't', b'o', b'a', b's', b't']),
-    WordBuffer::from_u8s([b't', b'o', b'd', b'a', b'y']),
-    WordBuffer::from_u8s([b't', b'o', b'd', b'd', b'y']),
-    WordBuffer::from_u8s([b't', b'o', b'k', b'e', b'n']),
-    WordBuffer::from_u8s([b't', b'o', b'n', b'a', b'l']),
-    WordBuffer::from_u8s([b't', b'o', b'n', b'g', b'a']),
-    WordBuffer::from_u8s([b't', b'o', b'n', b'i', b'c']),
-    WordBuffer::from_u8s([b't', b'o', b'o', b't', b'h']),
-    WordBuffer::from_u8s([b't', b'o', b'p', b'a', b'z']),
-    WordBuffer::from_u8s([b't', b'o', b'p', b'i', b'c']),
-    WordBuffer::from_u8s([b't', b'o', b'r', b'c', b'h']),
-    WordBuffer::from_u8s([b't', b'o', b'r', b's', b'o']),
-    WordBuffer::from_u8s([b't', b'o', b'r', b'u', b's']),
-    WordBuffer::from_u8s([b't', b'o', b't', b'a', b'l']),
-    WordBuffer::from_u8s([b't', b'o', b't', b'e', b'm']),
-    WordBuffer::from_u8s([b't', b'o', b'u', b'c', b'h']),
-    WordBuffer::from_u8s([b't', b'o', b'u', b'g', b'h']),
-    WordBuffer::from_u8s([b't', b'o', b'w', b'e', b'l']),
-    WordBuffer::from_u8s([b't', b'o', b'w', b'e', b'r']),
-    WordBuffer::from_u8s([b't', b'o', b'x', b'i', b'c']),
-    WordBuffer::from_u8s([b't', b'o', b'x', b'i', b'n']),
-    WordBuffer::from_u8s([b't', b'r', b'a', b'c', b'e']),
-    WordBuffer::from_u8s([b't', b'r', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b't', b'r', b'a', b'c', b't']),
-    WordBuffer::from_u8s([b't', b'r', b'a', b'd', b'e']),
-    WordBuffer::from_u8s([b't', b'r', b'a', b'i', b'l']),
-    WordBuffer::from_u8s([b't', b'r', b'a', b'i', b'n']),
-    WordBuffer::from_u8s([b't', b'r', b'a', b'i', b't']),
-    WordBuffer::from_u8s([b't', b'r', b'a', b'm', b'p']),
-    WordBuffer::from_u8s([b't', b'r', b'a', b's', b'h']),
-    WordBuffer::from_u8s([b't', b'r', b'a', b'w', b'l']),
-    WordBuffer::from_u8s([b't', b'r', b'e', b'a', b'd']),
-    WordBuffer::from_u8s([b't', b'r', b'e', b'a', b't']),
-    WordBuffer::from_u8s([b't', b'r', b'e', b'n', b'd']),
-    WordBuffer::from_u8s([b't', b'r', b'i', b'a', b'd']),
-    WordBuffer::from_u8s([b't', b'r', b'i', b'a', b'l']),
-    WordBuffer::from_u8s([b't', b'r', b'i', b'b', b'e']),
-    WordBuffer::from_u8s([b't', b'r', b'i', b'c', b'e']),
-    WordBuffer::from_u8s([b't', b'r', b'i', b'c', b'k']),
-    WordBuffer::from_u8s([b't', b'r', b'i', b'e', b'd']),
-    WordBuffer::from_u8s([b't', b'r', b'i', b'p', b'e']),
-    WordBuffer::from_u8s([b't', b'r', b'i', b't', b'e']),
-    WordBuffer::from_u8s([b't', b'r', b'o', b'l', b'l']),
-    WordBuffer::from_u8s([b't', b'r', b'o', b'o', b'p']),
-    WordBuffer::from_u8s([b't', b'r', b'o', b'p', b'e']),
-    WordBuffer::from_u8s([b't', b'r', b'o', b'u', b't']),
-    WordBuffer::from_u8s([b't', b'r', b'o', b'v', b'e']),
-    WordBuffer::from_u8s([b't', b'r', b'u', b'c', b'e']),
-    WordBuffer::from_u8s([b't', b'r', b'u', b'c', b'k']),
-    WordBuffer::from_u8s([b't', b'r', b'u', b'e', b'r']),
-    WordBuffer::from_u8s([b't', b'r', b'u', b'l', b'y']),
-    WordBuffer::from_u8s([b't', b'r', b'u', b'm', b'p']),
-    WordBuffer::from_u8s([b't', b'r', b'u', b'n', b'k']),
-    WordBuffer::from_u8s([b't', b'r', b'u', b's', b's']),
-    WordBuffer::from_u8s([b't', b'r', b'u', b's', b't']),
-    WordBuffer::from_u8s([b't', b'r', b'u', b't', b'h']),
-    WordBuffer::from_u8s([b't', b'r', b'y', b's', b't']),
-    WordBuffer::from_u8s([b't', b'u', b'b', b'a', b'l']),
-    WordBuffer::from_u8s([b't', b'u', b'b', b'e', b'r']),
-    WordBuffer::from_u8s([b't', b'u', b'l', b'i', b'p']),
-    WordBuffer::from_u8s([b't', b'u', b'l', b'l', b'e']),
-    WordBuffer::from_u8s([b't', b'u', b'm', b'o', b'r']),
-    WordBuffer::from_u8s([b't', b'u', b'n', b'i', b'c']),
-    WordBuffer::from_u8s([b't', b'u', b'r', b'b', b'o']),
-    WordBuffer::from_u8s([b't', b'u', b't', b'o', b'r']),
-    WordBuffer::from_u8s([b't', b'w', b'a', b'n', b'g']),
-    WordBuffer::from_u8s([b't', b'w', b'e', b'a', b'k']),
-    WordBuffer::from_u8s([b't', b'w', b'e', b'e', b'd']),
-    WordBuffer::from_u8s([b't', b'w', b'e', b'e', b't']),
-    WordBuffer::from_u8s([b't', b'w', b'i', b'c', b'e']),
-    WordBuffer::from_u8s([b't', b'w', b'i', b'n', b'e']),
-    WordBuffer::from_u8s([b't', b'w', b'i', b'r', b'l']),
-    WordBuffer::from_u8s([b't', b'w', b'i', b's', b't']),
-    WordBuffer::from_u8s([b't', b'w', b'i', b'x', b't']),
-    WordBuffer::from_u8s([b't', b'y', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'u', b'd', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'u', b'l', b'c', b'e', b'r']),
-    WordBuffer::from_u8s([b'u', b'l', b't', b'r', b'a']),
-    WordBuffer::from_u8s([b'u', b'm', b'b', b'r', b'a']),
-    WordBuffer::from_u8s([b'u', b'n', b'c', b'l', b'e']),
-    WordBuffer::from_u8s([b'u', b'n', b'c', b'u', b't']),
-    WordBuffer::from_u8s([b'u', b'n', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'u', b'n', b'd', b'i', b'd']),
-    WordBuffer::from_u8s([b'u', b'n', b'd', b'u', b'e']),
-    WordBuffer::from_u8s([b'u', b'n', b'f', b'e', b'd']),
-    WordBuffer::from_u8s([b'u', b'n', b'f', b'i', b't']),
-    WordBuffer::from_u8s([b'u', b'n', b'i', b'f', b'y']),
-    WordBuffer::from_u8s([b'u', b'n', b'i', b'o', b'n']),
-    WordBuffer::from_u8s([b'u', b'n', b'i', b't', b'e']),
-    WordBuffer::from_u8s([b'u', b'n', b'i', b't', b'y']),
-    WordBuffer::from_u8s([b'u', b'n', b'l', b'i', b't']),
-    WordBuffer::from_u8s([b'u', b'n', b'm', b'e', b't']),
-    WordBuffer::from_u8s([b'u', b'n', b's', b'e', b't']),
-    WordBuffer::from_u8s([b'u', b'n', b't', b'i', b'e']),
-    WordBuffer::from_u8s([b'u', b'n', b't', b'i', b'l']),
-    WordBuffer::from_u8s([b'u', b'n', b'w', b'e', b'd']),
-    WordBuffer::from_u8s([b'u', b'n', b'z', b'i', b'p']),
-    WordBuffer::from_u8s([b'u', b'p', b'p', b'e', b'r']),
-    WordBuffer::from_u8s([b'u', b'p', b's', b'e', b't']),
-    WordBuffer::from_u8s([b'u', b'r', b'b', b'a', b'n']),
-    WordBuffer::from_u8s([b'u', b'r', b'i', b'n', b'e']),
-    WordBuffer::from_u8s([b'u', b's', b'a', b'g', b'e']),
-    WordBuffer::from_u8s([b'u', b's', b'h', b'e', b'r']),
-    WordBuffer::from_u8s([b'u', b's', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'u', b's', b'u', b'a', b'l']),
-    WordBuffer::from_u8s([b'u', b's', b'u', b'r', b'p']),
-    WordBuffer::from_u8s([b'u', b't', b'i', b'l', b'e']),
-    WordBuffer::from_u8s([b'u', b't', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'v', b'a', b'g', b'u', b'e']),
-    WordBuffer::from_u8s([b'v', b'a', b'l', b'e', b't']),
-    WordBuffer::from_u8s([b'v', b'a', b'l', b'i', b'd']),
-    WordBuffer::from_u8s([b'v', b'a', b'l', b'o', b'r']),
-    WordBuffer::from_u8s([b'v', b'a', b'l', b'u', b'e']),
-    WordBuffer::from_u8s([b'v', b'a', b'l', b'v', b'e']),
-    WordBuffer::from_u8s([b'v', b'a', b'p', b'i', b'd']),
-    WordBuffer::from_u8s([b'v', b'a', b'p', b'o', b'r']),
-    WordBuffer::from_u8s([b'v', b'a', b'u', b'l', b't']),
-    WordBuffer::from_u8s([b'v', b'a', b'u', b'n', b't']),
-    WordBuffer::from_u8s([b'v', b'e', b'g', b'a', b'n']),
-    WordBuffer::from_u8s([b'v', b'e', b'n', b'o', b'm']),
-    WordBuffer::from_u8s([b'v', b'e', b'n', b'u', b'e']),
-    WordBuffer::from_u8s([b'v', b'e', b'r', b'g', b'e']),
-    WordBuffer::from_u8s([b'v', b'e', b'r', b's', b'e']),
-    WordBuffer::from_u8s([b'v', b'e', b'r', b's', b'o']),
-    WordBuffer::from_u8s([b'v', b'e', b'r', b'v', b'e']),
-    WordBuffer::from_u8s([b'v', b'i', b'c', b'a', b'r']),
-    WordBuffer::from_u8s([b'v', b'i', b'd', b'e', b'o']),
-    WordBuffer::from_u8s([b'v', b'i', b'g', b'i', b'l']),
-    WordBuffer::from_u8s([b'v', b'i', b'g', b'o', b'r']),
-    WordBuffer::from_u8s([b'v', b'i', b'l', b'l', b'a']),
-    WordBuffer::from_u8s([b'v', b'i', b'n', b'y', b'l']),
-    WordBuffer::from_u8s([b'v', b'i', b'o', b'l', b'a']),
-    WordBuffer::from_u8s([b'v', b'i', b'p', b'e', b'r']),
-    WordBuffer::from_u8s([b'v', b'i', b'r', b'a', b'l']),
-    WordBuffer::from_u8s([b'v', b'i', b'r', b'u', b's']),
-    WordBuffer::from_u8s([b'v', b'i', b's', b'i', b't']),
-    WordBuffer::from_u8s([b'v', b'i', b's', b'o', b'r']),
-    WordBuffer::from_u8s([b'v', b'i', b's', b't', b'a']),
-    WordBuffer::from_u8s([b'v', b'i', b't', b'a', b'l']),
-    WordBuffer::from_u8s([b'v', b'i', b'v', b'i', b'd']),
-    WordBuffer::from_u8s([b'v', b'i', b'x', b'e', b'n']),
-    WordBuffer::from_u8s([b'v', b'o', b'c', b'a', b'l']),
-    WordBuffer::from_u8s([b'v', b'o', b'd', b'k', b'a']),
-    WordBuffer::from_u8s([b'v', b'o', b'g', b'u', b'e']),
-    WordBuffer::from_u8s([b'v', b'o', b'i', b'c', b'e']),
-    WordBuffer::from_u8s([b'v', b'o', b'i', b'l', b'a']),
-    WordBuffer::from_u8s([b'v', b'o', b'm', b'i', b't']),
-    WordBuffer::from_u8s([b'v', b'o', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'v', b'o', b'u', b'c', b'h']),
-    WordBuffer::from_u8s([b'v', b'o', b'w', b'e', b'l']),
-    WordBuffer::from_u8s([b'v', b'y', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'w', b'a', b'c', b'k', b'y']),
-    WordBuffer::from_u8s([b'w', b'a', b'f', b'e', b'r']),
-    WordBuffer::from_u8s([b'w', b'a', b'g', b'e', b'r']),
-    WordBuffer::from_u8s([b'w', b'a', b'g', b'o', b'n']),
-    WordBuffer::from_u8s([b'w', b'a', b'i', b's', b't']),
-    WordBuffer::from_u8s([b'w', b'a', b'i', b'v', b'e']),
-    WordBuffer::from_u8s([b'w', b'a', b'l', b't', b'z']),
-    WordBuffer::from_u8s([b'w', b'a', b'r', b't', b'y']),
-    WordBuffer::from_u8s([b'w', b'a', b's', b't', b'e']),
-    WordBuffer::from_u8s([b'w', b'a', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'w', b'a', b't', b'e', b'r']),
-    WordBuffer::from_u8s([b'w', b'a', b'v', b'e', b'r']),
-    WordBuffer::from_u8s([b'w', b'a', b'x', b'e', b'n']),
-    WordBuffer::from_u8s([b'w', b'e', b'a', b'r', b'y']),
-    WordBuffer::from_u8s([b'w', b'e', b'a', b'v', b'e']),
-    WordBuffer::from_u8s([b'w', b'e', b'd', b'g', b'e']),
-    WordBuffer::from_u8s([b'w', b'e', b'e', b'd', b'y']),
-    WordBuffer::from_u8s([b'w', b'e', b'i', b'g', b'h']),
-    WordBuffer::from_u8s([b'w', b'e', b'i', b'r', b'd']),
-    WordBuffer::from_u8s([b'w', b'e', b'l', b'c', b'h']),
-    WordBuffer::from_u8s([b'w', b'e', b'l', b's', b'h']),
-    WordBuffer::from_u8s([b'w', b'e', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'w', b'h', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b'w', b'h', b'a', b'l', b'e']),
-    WordBuffer::from_u8s([b'w', b'h', b'a', b'r', b'f']),
-    WordBuffer::from_u8s([b'w', b'h', b'e', b'a', b't']),
-    WordBuffer::from_u8s([b'w', b'h', b'e', b'e', b'l']),
-    WordBuffer::from_u8s([b'w', b'h', b'e', b'l', b'p']),
-    WordBuffer::from_u8s([b'w', b'h', b'e', b'r', b'e']),
-    WordBuffer::from_u8s([b'w', b'h', b'i', b'c', b'h']),
-    WordBuffer::from_u8s([b'w', b'h', b'i', b'f', b'f']),
-    WordBuffer::from_u8s([b'w', b'h', b'i', b'l', b'e']),
-    WordBuffer::from_u8s([b'w', b'h', b'i', b'n', b'e']),
-    WordBuffer::from_u8s([b'w', b'h', b'i', b'n', b'y']),
-    WordBuffer::from_u8s([b'w', b'h', b'i', b'r', b'l']),
-    WordBuffer::from_u8s([b'w', b'h', b'i', b's', b'k']),
-    WordBuffer::from_u8s([b'w', b'h', b'i', b't', b'e']),
-    WordBuffer::from_u8s([b'w', b'h', b'o', b'l', b'e']),
-    WordBuffer::from_u8s([b'w', b'h', b'o', b'o', b'p']),
-    WordBuffer::from_u8s([b'w', b'h', b'o', b's', b'e']),
-    WordBuffer::from_u8s([b'w', b'i', b'd', b'e', b'n']),
-    WordBuffer::from_u8s([b'w', b'i', b'd', b'e', b'r']),
-    WordBuffer::from_u8s([b'w', b'i', b'd', b'o', b'w']),
-    WordBuffer::from_u8s([b'w', b'i', b'd', b't', b'h']),
-    WordBuffer::from_u8s([b'w', b'i', b'e', b'l', b'd']),
-    WordBuffer::from_u8s([b'w', b'i', b'g', b'h', b't']),
-    WordBuffer::from_u8s([b'w', b'i', b'l', b'l', b'y']),
-    WordBuffer::from_u8s([b'w', b'i', b'm', b'p', b'y']),
-    WordBuffer::from_u8s([b'w', b'i', b'n', b'c', b'e']),
-    WordBuffer::from_u8s([b'w', b'i', b'n', b'c', b'h']),
-    WordBuffer::from_u8s([b'w', b'i', b'n', b'd', b'y']),
-    WordBuffer::from_u8s([b'w', b'i', b's', b'e', b'r']),
-    WordBuffer::from_u8s([b'w', b'i', b's', b'p', b'y']),
-    WordBuffer::from_u8s([b'w', b'i', b't', b'c', b'h']),
-    WordBuffer::from_u8s([b'w', b'i', b't', b't', b'y']),
-    WordBuffer::from_u8s([b'w', b'o', b'k', b'e', b'n']),
-    WordBuffer::from_u8s([b'w', b'o', b'm', b'a', b'n']),
-    WordBuffer::from_u8s([b'w', b'o', b'm', b'e', b'n']),
-    WordBuffer::from_u8s([b'w', b'o', b'o', b'd', b'y']),
-    WordBuffer::from_u8s([b'w', b'o', b'o', b'e', b'r']),
-    WordBuffer::from_u8s([b'w', b'o', b'o', b'l', b'y']),
-    WordBuffer::from_u8s([b'w', b'o', b'o', b'z', b'y']),
-    WordBuffer::from_u8s([b'w', b'o', b'r', b'd', b'y']),
-    WordBuffer::from_u8s([b'w', b'o', b'r', b'l', b'd']),
-    WordBuffer::from_u8s([b'w', b'o', b'r', b'r', b'y']),
-    WordBuffer::from_u8s([b'w', b'o', b'r', b's', b'e']),
-    WordBuffer::from_u8s([b'w', b'o', b'r', b's', b't']),
-    WordBuffer::from_u8s([b'w', b'o', b'r', b't', b'h']),
-    WordBuffer::from_u8s([b'w', b'o', b'u', b'l', b'd']),
-    WordBuffer::from_u8s([b'w', b'o', b'u', b'n', b'd']),
-    WordBuffer::from_u8s([b'w', b'o', b'v', b'e', b'n']),
-    WordBuffer::from_u8s([b'w', b'r', b'a', b'c', b'k']),
-    WordBuffer::from_u8s([b'w', b'r', b'a', b't', b'h']),
-    WordBuffer::from_u8s([b'w', b'r', b'e', b'a', b'k']),
-    WordBuffer::from_u8s([b'w', b'r', b'e', b'c', b'k']),
-    WordBuffer::from_u8s([b'w', b'r', b'e', b's', b't']),
-    WordBuffer::from_u8s([b'w', b'r', b'i', b'n', b'g']),
-    WordBuffer::from_u8s([b'w', b'r', b'i', b's', b't']),
-    WordBuffer::from_u8s([b'w', b'r', b'i', b't', b'e']),
-    WordBuffer::from_u8s([b'w', b'r', b'o', b'n', b'g']),
-    WordBuffer::from_u8s([b'w', b'r', b'o', b't', b'e']),
-    WordBuffer::from_u8s([b'w', b'r', b'u', b'n', b'g']),
-    WordBuffer::from_u8s([b'w', b'r', b'y', b'l', b'y']),
-    WordBuffer::from_u8s([b'y', b'a', b'c', b'h', b't']),
-    WordBuffer::from_u8s([b'y', b'e', b'a', b'r', b'n']),
-    WordBuffer::from_u8s([b'y', b'e', b'a', b's', b't']),
-    WordBuffer::from_u8s([b'y', b'i', b'e', b'l', b'd']),
-    WordBuffer::from_u8s([b'y', b'o', b'u', b'n', b'g']),
-    WordBuffer::from_u8s([b'y', b'o', b'u', b't', b'h']),
-    WordBuffer::from_u8s([b'z', b'e', b'b', b'r', b'a']),
-    WordBuffer::from_u8s([b'z', b'e', b's', b't', b'y']),
-    WordBuffer::from_u8s([b'z', b'o', b'n', b'a', b'l']),
-];
@@ -0,0 +1,241 @@
+use voladdress::{Safe, VolAddress};
+
+use crate::{
+    dma::DmaControl,
+    interrupt::{self, Interrupt},
+    mmio::{
+        DMA1_CONTROL, DMA1_DEST, DMA1_SRC, DMA2_CONTROL, DMA2_DEST, DMA2_SRC, FIFO_A, FIFO_B,
+        SOUNDCNT_H, TM0CNT_H, TM0CNT_L, TM1CNT_H, TM1CNT_L,
+    },
+    timer::{reload_for_hz, TimerControl},
+};
+
+/// How many words the DMA copies into a FIFO each time it runs low
+const FIFO_REFILL_WORDS: usize = 4;
+
+/// Channel A is clocked by timer 0 and fed by DMA 1, and channel B
+/// by timer 1 and DMA 2.
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    A = 0,
+    B = 1,
+}
+
+/// Forces the alignment of `include_bytes!`, which the DMA needs
+#[repr(C, align(4))]
+pub struct Aligned<T: ?Sized>(pub T);
+
+/// Includes a PCM blob generated by `build.rs` as a `Pcm`
+#[macro_export]
+macro_rules! include_pcm {
+    ($path:expr) => {{
+        static BYTES: &$crate::direct_sound::Aligned<[u8]> =
+            &$crate::direct_sound::Aligned(*include_bytes!($path));
+        $crate::direct_sound::Pcm::from_bytes(BYTES)
+    }};
+}
+
+/*
+   Signed 8-bit mono samples, in the format `build.rs` converts WAV
+   files to: the sample rate as a little endian word, followed by the
+   samples, padded with silence to a multiple of the 16 bytes the FIFO
+   is refilled with at a time.
+*/
+#[derive(Clone, Copy)]
+pub struct Pcm {
+    samples: &'static [u32],
+    sample_rate: u32,
+}
+
+impl Pcm {
+    pub fn from_bytes(bytes: &'static Aligned<[u8]>) -> Self {
+        let bytes = &bytes.0;
+        assert!(bytes.len() >= 4 && (bytes.len() - 4).is_multiple_of(FIFO_REFILL_WORDS * 4));
+
+        let words =
+            unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast::<u32>(), bytes.len() / 4) };
+        Self {
+            samples: &words[1..],
+            sample_rate: words[0],
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+}
+
+/// What the FIFO is fed once a sample that doesn't loop has run out, so
+/// the last of it gets played before the channel is stopped
+static SILENCE: [u32; 2 * FIFO_REFILL_WORDS] = [0; 2 * FIFO_REFILL_WORDS];
+
+struct Playback {
+    /// Empty when nothing is playing
+    samples: &'static [u32],
+    /// How far the DMA has got, in words
+    position: usize,
+    looping: bool,
+    /// Whether the samples have run out, and the FIFO is being fed silence
+    draining: bool,
+}
+
+impl Playback {
+    const STOPPED: Self = Self {
+        samples: &[],
+        position: 0,
+        looping: false,
+        draining: false,
+    };
+}
+
+static mut PLAYBACK: [Playback; 2] = [Playback::STOPPED, Playback::STOPPED];
+
+/// Routes both channels to both speakers at full volume and installs the
+/// DMA interrupt handlers that keep track of them. The sound hardware has
+/// to have been switched on with `sound::init` first.
+pub fn init() {
+    let mut mix = SOUNDCNT_H.read();
+    for channel in [Channel::A, Channel::B] {
+        mix = mix
+            .direct_sound_full_volume(channel, true)
+            .direct_sound_output(channel, true, true)
+            .direct_sound_timer(channel, channel as u16)
+            .direct_sound_reset(channel);
+    }
+
+    SOUNDCNT_H.write(mix);
+
+    interrupt::set_handler(Interrupt::DMA1, Some(refilled_a));
+    interrupt::set_handler(Interrupt::DMA2, Some(refilled_b));
+    interrupt::enable(Interrupt::DMA1 | Interrupt::DMA2);
+}
+
+/// Starts playing `pcm` on `channel`, cutting off whatever was playing. Looping
+/// samples carry on from the start when they reach the end, until `stop`ped.
+pub fn play(channel: Channel, pcm: Pcm, looping: bool) {
+    stop(channel);
+
+    interrupt::without_interrupts(|| {
+        let playback = unsafe { playback(channel) };
+        playback.samples = pcm.samples;
+        playback.position = 0;
+        playback.looping = looping;
+        playback.draining = false;
+    });
+
+    let (reload, control) = timer(channel);
+    reload.write(reload_for_hz(pcm.sample_rate));
+    control.write(TimerControl::new().enabled(true));
+
+    unsafe { start_dma(channel, pcm.samples.as_ptr()) };
+}
+
+pub fn stop(channel: Channel) {
+    interrupt::without_interrupts(|| {
+        *unsafe { playback(channel) } = Playback::STOPPED;
+        stop_hardware(channel);
+    });
+}
+
+pub fn is_playing(channel: Channel) -> bool {
+    interrupt::without_interrupts(|| !unsafe { playback(channel) }.samples.is_empty())
+}
+
+/// # Safety
+///
+/// The DMA interrupt handlers also use this, so interrupts must be off, or
+/// it must be called from one of the handlers.
+unsafe fn playback(channel: Channel) -> &'static mut Playback {
+    let playback = &raw mut PLAYBACK;
+    &mut (*playback)[channel as usize]
+}
+
+fn timer(
+    channel: Channel,
+) -> (
+    VolAddress<u16, Safe, Safe>,
+    VolAddress<TimerControl, Safe, Safe>,
+) {
+    match channel {
+        Channel::A => (TM0CNT_L, TM0CNT_H),
+        Channel::B => (TM1CNT_L, TM1CNT_H),
+    }
+}
+
+/// Points the channel's DMA at `src` and lets the FIFO pull from it whenever it runs low
+///
+/// # Safety
+///
+/// `src` must be word aligned, and stay valid until the DMA is stopped or restarted.
+unsafe fn start_dma(channel: Channel, src: *const u32) {
+    let (src_reg, dest_reg, control, fifo) = match channel {
+        Channel::A => (DMA1_SRC, DMA1_DEST, DMA1_CONTROL, FIFO_A),
+        Channel::B => (DMA2_SRC, DMA2_DEST, DMA2_CONTROL, FIFO_B),
+    };
+
+    // The source is only latched when the DMA is enabled, so it has to be turned off first
+    control.write(DmaControl::empty());
+    src_reg.write(src as usize);
+    dest_reg.write(fifo.as_usize());
+    control.write(
+        DmaControl::DEST_FIXED
+            | DmaControl::REPEAT
+            | DmaControl::TRANSFER_32BIT
+            | DmaControl::START_SPECIAL
+            | DmaControl::IRQ_ON_END
+            | DmaControl::ENABLE,
+    );
+}
+
+fn stop_hardware(channel: Channel) {
+    let control = match channel {
+        Channel::A => DMA1_CONTROL,
+        Channel::B => DMA2_CONTROL,
+    };
+
+    unsafe { control.write(DmaControl::empty()) };
+    timer(channel).1.write(TimerControl::new());
+    SOUNDCNT_H.write(SOUNDCNT_H.read().direct_sound_reset(channel));
+}
+
+/*
+   The DMA interrupt fires after every refill of the FIFO, which is
+   how we know where the DMA has got to. Once it's read the last of the
+   samples, it's either pointed back at the start or at some silence,
+   rather than wandering off the end. The FIFO holds two refills, so the
+   channel is only stopped once both of those are silence, and the end
+   of the sample has been played.
+*/
+fn refilled(channel: Channel) {
+    let playback = unsafe { playback(channel) };
+    if playback.samples.is_empty() {
+        return;
+    }
+
+    playback.position += FIFO_REFILL_WORDS;
+    if playback.position < playback.samples.len() {
+        return;
+    }
+
+    if playback.looping {
+        playback.position = 0;
+        unsafe { start_dma(channel, playback.samples.as_ptr()) };
+    } else if !playback.draining {
+        playback.samples = &SILENCE;
+        playback.position = 0;
+        playback.draining = true;
+        unsafe { start_dma(channel, SILENCE.as_ptr()) };
+    } else {
+        *playback = Playback::STOPPED;
+        stop_hardware(channel);
+    }
+}
+
+fn refilled_a() {
+    refilled(Channel::A);
+}
+
+fn refilled_b() {
+    refilled(Channel::B);
+}
@@ -0,0 +1,33 @@
+use crate::mmio::{DMA3_CONTROL, DMA3_COUNT, DMA3_DEST, DMA3_SRC};
+
+bitflags::bitflags! {
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct DmaControl: u16 {
+        const DEST_DECREMENT = 1 << 5;
+        const DEST_FIXED = 2 << 5;
+        const DEST_INCREMENT_RELOAD = 3 << 5;
+        const SRC_DECREMENT = 1 << 7;
+        const SRC_FIXED = 2 << 7;
+        const REPEAT = 1 << 9;
+        const TRANSFER_32BIT = 1 << 10;
+        const START_VBLANK = 1 << 12;
+        const START_HBLANK = 2 << 12;
+        const START_SPECIAL = 3 << 12;
+        const IRQ_ON_END = 1 << 14;
+        const ENABLE = 1 << 15;
+    }
+}
+
+/// Copies `count` words from `src` to `dest` using DMA 3. The CPU is halted
+/// until the transfer is done, so this returns once the copy is complete.
+///
+/// # Safety
+///
+/// Both pointers must be word aligned and valid for `count` words.
+pub unsafe fn copy_u32(src: *const u32, dest: *mut u32, count: u16) {
+    DMA3_SRC.write(src as usize);
+    DMA3_DEST.write(dest as usize);
+    DMA3_COUNT.write(count);
+    DMA3_CONTROL.write(DmaControl::TRANSFER_32BIT | DmaControl::ENABLE);
+}
@@ -1,23 +1,46 @@
 use wordboy::{
-    input::KeyInput,
-    mmio::OBJ_ATTRS,
-    video::{ObjAttr, TileSize},
+    input::{Buttons, Key},
+    math::{self, QUARTER_TURN},
+    sound::SoundPlayer,
+    text::{Align, Rect, TextLayer},
+    video::{
+        screen_block, AffineMatrix, BlendLayers, Fade, FadeColor, ObjAttr, ShadowOam, TextEntry,
+        TileSize, BLEND_MAX, SCREEN_BLOCK_WIDTH,
+    },
 };
 
-use crate::{
+use wordboy_rules::{
+    board::{Board, Difficulty, LetterMatch, Rejection, State, MAX_GUESS_COUNT, MIN_GUESS_COUNT},
     dictionary,
-    utils::{ArrayVec, AsciiChar, WordBuffer},
+    utils::{AsciiChar, WordBuffer, MAX_WORD_LENGTH, MIN_WORD_LENGTH},
+};
+
+use crate::{
+    sfx,
+    stats::{Stats, FAILED_BUCKET},
 };
 
 const SCREEN_WIDTH: i16 = 240;
 const SCREEN_HEIGHT: i16 = 160;
 const TILE_PADDING: i16 = 4;
-const TILE_COL_COUNT: i16 = 5;
-const TILE_ROW_COUNT: usize = 6;
 const TILE_WIDTH: i16 = 16;
-const ROW_WIDTH: i16 = (TILE_COL_COUNT * TILE_WIDTH) + (TILE_PADDING * (TILE_COL_COUNT - 1));
-const ROW_OFFSET: i16 = (SCREEN_WIDTH - ROW_WIDTH) / 2;
+const KEYBOARD_Y_OFFSET: i16 = SCREEN_HEIGHT - TILE_WIDTH - 12;
+const GRID_Y_OFFSET: i16 = TILE_PADDING;
+const GRID_MIN_ROW_PITCH: i16 = TILE_WIDTH + 1;
 const KBD_ANIMATION_SPEED: u16 = 4;
+const REJECTION_MESSAGE_TICKS: u16 = 90;
+const REJECTION_MESSAGE_Y_OFFSET: i16 = (SCREEN_HEIGHT - TILE_WIDTH) / 2;
+const REJECTION_SHAKE_TICKS_PER_STEP: u16 = 3;
+const REJECTION_SHAKE_STEPS: [i16; 8] = [-4, 4, -4, 4, -2, 2, -1, 1];
+const TOAST_TICKS: u16 = 90;
+/// Each tile turns over halfway by the time its reveal sound plays
+const FLIP_STAGGER_TICKS: u16 = sfx::REVEAL_DELAY as u16;
+const FLIP_TICKS: u16 = 2 * FLIP_STAGGER_TICKS;
+/// How far towards black the board is dimmed behind the popup, in sixteenths
+const DIM_LEVEL: u16 = 8;
+const DIM_TICKS: u16 = 16;
+/// The middle row of the toast's box, in tiles
+const TOAST_ROW: usize = 9;
 
 const GREEN_PALETTE: u16 = 1;
 const YELLOW_PALETTE: u16 = 2;
@@ -25,54 +48,145 @@ const GREY_PALETTE: u16 = 3;
 const BLACK_PALETTE: u16 = 4;
 const POPUP_WIN_PALETTE: u16 = 5;
 const POPUP_LOSE_PALETTE: u16 = 6;
+const FRAME_PALETTE: u16 = 7;
+const TEXT_PALETTE: u16 = 8;
+const TEXT_GREEN_PALETTE: u16 = 9;
+const TEXT_GREY_PALETTE: u16 = 10;
+const CURSOR_PALETTE: u16 = 11;
+
+/*
+   The popup and the frame around the grid are drawn on backgrounds, using
+   the same tiles and palettes as the sprites. The popup is in front of the
+   grid, so the grid's sprites are pushed back behind it. Text goes on a
+   background of its own in front of everything else, with the font loaded
+   after the sprites' tiles. Backgrounds stay put until they're cleared, so
+   the `render_*` functions that draw on them only need calling once, after
+   the screen has been cleared.
+*/
+pub const BG_CHAR_BLOCK: usize = 0;
+pub const TEXT_BG: usize = 0;
+pub const TEXT_SCREEN_BLOCK: usize = 29;
+pub const POPUP_BG: usize = 1;
+pub const POPUP_SCREEN_BLOCK: usize = 31;
+pub const FRAME_BG: usize = 2;
+pub const FRAME_SCREEN_BLOCK: usize = 30;
+pub const FONT_TILE_OFFSET: u16 = 320;
+const TEXT: TextLayer = TextLayer::new(TEXT_SCREEN_BLOCK, FONT_TILE_OFFSET);
+const GRID_PRIORITY: u16 = 1;
+/// Everything but the popup and the text in front of it
+const DIMMED_LAYERS: BlendLayers = BlendLayers::OBJ.union(BlendLayers::background(FRAME_BG));
 
 const NULL_TILE: u16 = 47;
-
-pub struct SplashScreen(u16);
+/// Where the small keys of the QWERTY keyboard start in the sprite tiles,
+/// just after the spritesheet
+pub const KEY_TILE_OFFSET: u16 = 257;
+const KEY_WIDTH: i16 = 8;
+const KEY_PITCH: i16 = KEY_WIDTH + 2;
+/// How far the selected key, which is drawn bigger than the others, sticks out past its key
+const KEY_OVERHANG: i16 = (TILE_WIDTH - KEY_WIDTH) / 2;
+/// Leaves room below the bottom row for the selected key to stick out
+const QWERTY_Y_OFFSET: i16 = SCREEN_HEIGHT - 3 * KEY_PITCH - KEY_OVERHANG;
+/// The keys of the QWERTY keyboard, where `>` is enter and `<` is delete
+const QWERTY_ROWS: [&[u8]; 3] = [b"QWERTYUIOP", b"ASDFGHJKL", b">ZXCVBNM<"];
+const ENTER_KEY: u8 = b'>';
+const DELETE_KEY: u8 = b'<';
+const DIGIT_SPRITE_OFFSET: u16 = 51;
+
+pub struct SplashScreen {
+    tick: u16,
+    options: Options,
+}
 
 impl SplashScreen {
-    pub fn new() -> Self {
-        Self(0)
+    pub fn new(options: Options) -> Self {
+        Self { tick: 0, options }
     }
 
-    pub fn update(&mut self) {
-        self.0 += 1;
+    pub fn options(&self) -> Options {
+        self.options
     }
 
-    pub fn render(&self) {
-        fn draw_text(
-            text: &str,
-            y_offset: i16,
-            palette_offset: u16,
-            attr_allocator: &mut ObjAttrAllocator,
-        ) {
-            let len = text.len() as i16;
-            let x_left = (SCREEN_WIDTH - ((len * TILE_WIDTH) + (TILE_PADDING * (len - 1)))) / 2;
-            for (i, letter) in text.chars().enumerate() {
-                let x = x_left + (i as i16) * (TILE_WIDTH + TILE_PADDING);
-                let y = y_offset;
+    pub fn toggle_difficulty(&mut self) {
+        self.options.difficulty = match self.options.difficulty {
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Normal,
+        };
+    }
 
-                let obj = ObjAttr::new()
-                    .size(TileSize::SIZE_16X16)
-                    .tile(AsciiChar::from_u8(letter as u8).tile_index())
-                    .palette((palette_offset + i as u16) % 3 + 1)
-                    .x(x)
-                    .y(y);
+    pub fn toggle_keyboard(&mut self) {
+        self.options.keyboard = match self.options.keyboard {
+            Keyboard::Carousel => Keyboard::Qwerty,
+            Keyboard::Qwerty => Keyboard::Carousel,
+        };
+    }
 
-                attr_allocator.allocate_and_write(obj);
-            }
-        }
+    pub fn adjust_guess_count(&mut self, delta: isize) {
+        self.options.guess_count = self
+            .options
+            .guess_count
+            .saturating_add_signed(delta)
+            .clamp(MIN_GUESS_COUNT, MAX_GUESS_COUNT);
+    }
+
+    pub fn adjust_word_length(&mut self, delta: isize) {
+        self.options.word_length = self
+            .options
+            .word_length
+            .saturating_add_signed(delta)
+            .clamp(MIN_WORD_LENGTH, MAX_WORD_LENGTH);
+    }
 
-        let tick = self.0 / 32;
-        let mut attr_allocator = ObjAttrAllocator::new();
+    pub fn update(&mut self) {
+        self.tick += 1;
+    }
 
-        draw_text("WORD", 40, tick, &mut attr_allocator);
+    pub fn render(&self, oam: &mut ShadowOam) {
+        // The rows share a pitch, with a little more room around the options,
+        // and all of them fit above the start button
+        const TOP: i16 = 20;
+        const ROW_PITCH: i16 = TILE_WIDTH + TILE_PADDING;
+
+        let tick = self.tick / 32;
+        oam.clear();
+
+        draw_text("WORD", TOP, |i| (tick + i) % 3 + 1, oam);
 
         draw_text(
             "BOY",
-            40 + TILE_WIDTH + TILE_PADDING,
-            tick + 4,
-            &mut attr_allocator,
+            TOP + ROW_PITCH,
+            |i| (tick + 4 + i) % 3 + 1,
+            oam,
+        );
+
+        // The size of the grid as letters x guesses, followed by
+        // the hard mode toggle which is lit up when enabled
+        let hard_palette = match self.options.difficulty {
+            Difficulty::Normal => BLACK_PALETTE,
+            Difficulty::Hard => GREEN_PALETTE,
+        };
+
+        let mut options = *b"0X0 HARD";
+        options[0] += self.options.word_length as u8;
+        options[2] += self.options.guess_count as u8;
+
+        draw_text(
+            core::str::from_utf8(&options).unwrap(),
+            TOP + 2 * ROW_PITCH + TILE_PADDING,
+            |i| if i < 3 { GREY_PALETTE } else { hard_palette },
+            oam,
+        );
+
+        // The keyboard toggle, lit up when the QWERTY keyboard is picked
+        let qwerty_palette = match self.options.keyboard {
+            Keyboard::Carousel => BLACK_PALETTE,
+            Keyboard::Qwerty => GREEN_PALETTE,
+        };
+
+        draw_text(
+            "QWERTY",
+            TOP + 3 * ROW_PITCH + 2 * TILE_PADDING,
+            |_| qwerty_palette,
+            oam,
         );
 
         // Start button
@@ -86,286 +200,849 @@ impl SplashScreen {
                 .x(start_off_x + (i as i16) * TILE_WIDTH)
                 .y(start_off_y);
 
-            attr_allocator.allocate_and_write(obj);
+            oam.push(obj);
+        }
+    }
+
+    /// Lists the buttons for the options below the start button
+    pub fn render_text(&self) {
+        TEXT.draw(
+            "SELECT HARD MODE  L KEYBOARD\nB HELP  R STATS",
+            Rect::new(0, 17, 30, 2),
+            Align::Center,
+            TEXT_GREY_PALETTE,
+        );
+    }
+}
+
+/// How to play, and which buttons do what on the picked keyboard
+pub fn render_help(keyboard: Keyboard) {
+    let controls = match keyboard {
+        Keyboard::Carousel => {
+            "A types a letter, B deletes one and UP enters the guess. L and R jump along \
+             the letters, and SELECT skips used ones. Hold SELECT to move in the guess."
+        }
+        Keyboard::Qwerty => {
+            "The D-pad picks a key and A presses it, with > entering the guess and < \
+             deleting. Hold SELECT with LEFT or RIGHT to move in the guess."
+        }
+    };
+
+    let paragraphs = [
+        "Guess the hidden word. Green letters are in the right spot, yellow ones are \
+         elsewhere in the word, and grey ones aren't in it.",
+        controls,
+        "Hard mode makes every hint count.",
+    ];
+    /// The row of the back button, which the paragraphs stop short of
+    const BACK_ROW: usize = 18;
+
+    TEXT.draw(
+        "HOW TO PLAY",
+        Rect::new(0, 1, 30, 1),
+        Align::Center,
+        TEXT_GREEN_PALETTE,
+    );
+
+    let mut y = 3;
+    for paragraph in paragraphs {
+        let area = Rect::new(1, y, 28, BACK_ROW.saturating_sub(y));
+        y += TEXT.draw(paragraph, area, Align::Left, TEXT_PALETTE) + 1;
+    }
+
+    TEXT.draw(
+        "B BACK",
+        Rect::new(0, BACK_ROW, 30, 1),
+        Align::Center,
+        TEXT_GREY_PALETTE,
+    );
+}
+
+pub struct StatsScreen {
+    stats: Stats,
+    /// How many guesses get a row, which covers the guesses allowed now
+    /// and any wins from games that allowed more
+    guess_rows: usize,
+}
+
+impl StatsScreen {
+    /// Where the totals go, to the right of the bars
+    const COLUMN: Rect = Rect::new(23, 2, 7, 16);
+
+    pub fn new(stats: Stats, guess_count: usize) -> Self {
+        let most_guesses = stats.distribution[..FAILED_BUCKET]
+            .iter()
+            .rposition(|&count| count != 0)
+            .map_or(0, |i| i + 1);
+
+        Self {
+            stats,
+            guess_rows: guess_count.max(most_guesses),
+        }
+    }
+
+    pub fn render(&self, oam: &mut ShadowOam) {
+        const BAR_X_OFFSET: i16 = 8 + TILE_WIDTH + TILE_PADDING;
+        const BAR_MAX_LENGTH: i16 = 6;
+        const TOP: i16 = 12;
+
+        oam.clear();
+
+        // The rows only get packed together when there are lots of guesses
+        let rows = self.guess_rows as i16 + 1;
+        let row_spacing = ((SCREEN_HEIGHT - TOP - TILE_WIDTH) / (rows - 1)).min(TILE_WIDTH + 2);
+
+        let distribution = &self.stats.distribution;
+        let max_count = distribution.iter().copied().max().unwrap_or(0).max(1) as u32;
+
+        // Shorten the bars when the counts get long, so that the longest
+        // bar's count still fits in front of the column of totals
+        let mut digits = [0; 5];
+        let max_digits = format_number(max_count as u16, &mut digits).len() as i16;
+        let room = (Self::COLUMN.x * 8) as i16 - BAR_X_OFFSET - TILE_PADDING;
+        let bar_max_length =
+            ((room - max_digits * TILE_WIDTH) / TILE_WIDTH).clamp(1, BAR_MAX_LENGTH) as u32;
+
+        let buckets = (0..self.guess_rows).chain([FAILED_BUCKET]);
+        for (row, i) in buckets.enumerate() {
+            let count = &distribution[i];
+            let y = TOP + (row as i16) * row_spacing;
+
+            // Label each row with the number of guesses, or an X for failed games
+            let (label, bar_palette) = if i == FAILED_BUCKET {
+                (AsciiChar::from_u8(b'X'), GREY_PALETTE)
+            } else {
+                (AsciiChar::from_u8(b'1' + i as u8), GREEN_PALETTE)
+            };
+
+            let obj = ObjAttr::new()
+                .size(TileSize::SIZE_16X16)
+                .tile(label.tile_index())
+                .palette(BLACK_PALETTE)
+                .x(8)
+                .y(y);
+
+            oam.push(obj);
+
+            // Any non-zero count gets at least one tile so that it's visible
+            let length = match *count as u32 {
+                0 => 0,
+                count => (count * bar_max_length / max_count).max(1),
+            };
+
+            for j in 0..length {
+                let obj = ObjAttr::new()
+                    .size(TileSize::SIZE_16X16)
+                    .tile(AsciiChar::NULL.tile_index())
+                    .palette(bar_palette)
+                    .x(BAR_X_OFFSET + (j as i16) * TILE_WIDTH)
+                    .y(y);
+
+                oam.push(obj);
+            }
+
+            let number_x = BAR_X_OFFSET + (length as i16) * TILE_WIDTH + TILE_PADDING;
+            draw_number(*count, number_x, y, BLACK_PALETTE, oam);
+        }
+    }
+
+    /// Titles the screen, and sums up the stats in a column to the right of the bars
+    pub fn render_text(&self) {
+        let area = Rect::new(0, 0, Self::COLUMN.x, 1);
+        TEXT.draw("STATS", area, Align::Center, TEXT_PALETTE);
+
+        let stats = &self.stats;
+        let wins = stats
+            .played
+            .saturating_sub(stats.distribution[FAILED_BUCKET]);
+        let win_percent = match stats.played {
+            0 => 0,
+            played => (wins as u32 * 100 / played as u32) as u16,
+        };
+
+        let summary = [
+            ("PLAYED", stats.played),
+            ("WIN %", win_percent),
+            ("STREAK", stats.current_streak),
+            ("BEST", stats.max_streak),
+        ];
+
+        for (i, (label, value)) in summary.into_iter().enumerate() {
+            let y = Self::COLUMN.y + 4 * i;
+            let mut digits = [0; 5];
+            let area = Rect::new(Self::COLUMN.x, y, Self::COLUMN.width, 1);
+            TEXT.draw(label, area, Align::Center, TEXT_GREY_PALETTE);
+
+            let area = Rect::new(Self::COLUMN.x, y + 1, Self::COLUMN.width, 1);
+            TEXT.draw(
+                format_number(value, &mut digits),
+                area,
+                Align::Center,
+                TEXT_PALETTE,
+            );
         }
     }
 }
 
 pub struct Game {
     instance: Instance,
-    prev_input: KeyInput,
     tick: u16,
+    /// Whether select has been used to move the cursor in the guess since it was pressed
+    select_chorded: bool,
+    toast: Option<Toast>,
+    /// Darkens the board once the popup is up
+    dim: Option<Fade>,
 }
 
 impl Game {
-    pub fn new(seed: u16) -> Self {
-        let word = dictionary::random_word(seed as usize);
+    pub fn new(seed: u16, options: Options) -> Self {
+        let word = dictionary::random_word(options.word_length, seed as usize);
         Self {
-            instance: Instance::new(word),
-            prev_input: KeyInput(0),
+            instance: Instance::new(word, options),
             tick: 0,
+            select_chorded: false,
+            toast: None,
+            dim: None,
         }
     }
 
     pub fn state(&self) -> State {
-        self.instance.state()
+        self.instance.board.state()
+    }
+
+    /// How far the board has been darkened behind the popup, in sixteenths
+    pub fn dim_level(&self) -> u16 {
+        self.dim.as_ref().map_or(0, Fade::level)
+    }
+
+    /// Darkens the board the rest of the way from behind the popup, leaving
+    /// the popup as it is
+    pub fn fade_out_board(&self, ticks: u16) -> Fade {
+        Fade::new(
+            FadeColor::Black,
+            DIMMED_LAYERS,
+            self.dim_level(),
+            BLEND_MAX,
+            ticks,
+        )
+    }
+
+    /// Takes the board away, leaving the popup and the text in front of it
+    pub fn clear_board(&self, oam: &mut ShadowOam) {
+        oam.clear();
+        oam.commit();
+        let area = Rect::new(0, 0, SCREEN_BLOCK_WIDTH, SCREEN_BLOCK_WIDTH);
+        clear_bg_area(FRAME_SCREEN_BLOCK, area);
+    }
+
+    /// Whether the last guess has been revealed and the board has finished
+    /// dimming behind the popup
+    pub fn is_popup_settled(&self) -> bool {
+        self.instance.reveal.is_none() && self.dim.as_ref().is_some_and(Fade::is_finished)
+    }
+
+    pub fn guesses_used(&self) -> usize {
+        self.instance.board.guesses_used()
+    }
+
+    pub fn update(&mut self, buttons: &Buttons, sound: &mut SoundPlayer) {
+        self.animate(buttons);
+
+        if buttons.pressed(Key::A) {
+            self.instance.input(Input::Char, sound);
+        }
+
+        if buttons.pressed(Key::B) {
+            self.instance.input(Input::Delete, sound);
+        }
+
+        // Holding select turns left and right into moving the cursor
+        // within the guess, instead of around the keyboard
+        if buttons.pressed(Key::SELECT) {
+            self.select_chorded = false;
+        }
+
+        if buttons.repeated(Key::LEFT) {
+            if buttons.held(Key::SELECT) {
+                self.select_chorded = true;
+                self.instance.move_guess_cursor(-1, sound);
+            } else {
+                self.instance.input(Input::CursorLeft, sound);
+            }
+        }
+
+        if buttons.repeated(Key::RIGHT) {
+            if buttons.held(Key::SELECT) {
+                self.select_chorded = true;
+                self.instance.move_guess_cursor(1, sound);
+            } else {
+                self.instance.input(Input::CursorRight, sound);
+            }
+        }
+
+        match self.instance.keyboard {
+            Keyboard::Carousel => {
+                if buttons.pressed(Key::UP) {
+                    self.instance.input(Input::Submit, sound);
+                }
+
+                if buttons.repeated(Key::L) {
+                    self.instance.input(Input::JumpLeft, sound);
+                }
+
+                if buttons.repeated(Key::R) {
+                    self.instance.input(Input::JumpRight, sound);
+                }
+
+                // Select toggles on release, so that using it to move
+                // the cursor within the guess doesn't toggle it too
+                if buttons.released(Key::SELECT) && !self.select_chorded {
+                    self.instance.input(Input::ToggleSkipEliminated, sound);
+                    self.show_toast(if self.instance.skip_eliminated {
+                        "SKIPPING USED LETTERS"
+                    } else {
+                        "SHOWING ALL LETTERS"
+                    });
+                }
+            }
+            Keyboard::Qwerty => {
+                if buttons.repeated(Key::UP) {
+                    self.instance.input(Input::CursorUp, sound);
+                }
+
+                if buttons.repeated(Key::DOWN) {
+                    self.instance.input(Input::CursorDown, sound);
+                }
+            }
+        }
+
+        // The rejection message and the popup at the end of the game
+        // take the toast's place
+        if self.instance.rejection.is_some() || self.state() != State::InProgress {
+            self.hide_toast();
+        }
     }
 
-    pub fn update(&mut self, input: KeyInput) {
+    /// Moves the animations on by a frame. This is done by `update`, but
+    /// also needs doing on its own once the game is over.
+    pub fn animate(&mut self, buttons: &Buttons) {
         self.tick += 1;
 
         if self.instance.keyboard_anim_offset != 0 {
-            // Diminish the keyboard animation offset towards zero
+            // Diminish the keyboard animation offset towards zero. When the
+            // cursor is moving faster than the animation, such as when a
+            // direction is held, it speeds up so that it doesn't fall behind.
             let sign = self.instance.keyboard_anim_offset.signum();
             let abs_offset = self.instance.keyboard_anim_offset.abs();
-            let max_diminish = abs_offset.min(KBD_ANIMATION_SPEED as i16);
+            let speed = (KBD_ANIMATION_SPEED as i16).max(abs_offset / buttons.repeat_rate() as i16);
+            let max_diminish = abs_offset.min(speed);
             self.instance.keyboard_anim_offset -= sign * max_diminish;
         }
 
-        if input.a_once(self.prev_input) {
-            self.instance.input(Input::Char);
+        if let Some(animation) = &mut self.instance.rejection {
+            animation.tick += 1;
+            if animation.tick >= REJECTION_MESSAGE_TICKS {
+                self.instance.rejection = None;
+            }
         }
 
-        if input.b_once(self.prev_input) {
-            self.instance.input(Input::Delete);
+        if let Some(toast) = &mut self.toast {
+            toast.tick += 1;
+            if toast.tick >= TOAST_TICKS {
+                self.hide_toast();
+            }
         }
 
-        if input.left_once(self.prev_input) {
-            self.instance.input(Input::CursorLeft);
+        if let Some(animation) = &mut self.instance.reveal {
+            animation.tick += 1;
+            if animation.is_finished(self.instance.board.word().len()) {
+                self.instance.reveal = None;
+            }
         }
 
-        if input.right_once(self.prev_input) {
-            self.instance.input(Input::CursorRight);
+        if self.dim.is_none() && self.instance.is_popup_shown() {
+            self.instance.render_popup();
+            let dim = Fade::new(FadeColor::Black, DIMMED_LAYERS, 0, DIM_LEVEL, DIM_TICKS);
+            self.dim = Some(dim);
         }
 
-        if input.up_once(self.prev_input) {
-            self.instance.input(Input::Submit);
+        if let Some(dim) = &mut self.dim {
+            dim.tick();
         }
+    }
+
+    /// Pops up a message over the middle of the grid for a moment
+    fn show_toast(&mut self, message: &'static str) {
+        self.hide_toast();
+        self.instance.rejection = None;
+
+        let toast = Toast { message, tick: 0 };
+        let area = toast.area();
+        draw_panel(
+            POPUP_SCREEN_BLOCK,
+            area.x,
+            area.y,
+            area.width,
+            area.height,
+            FRAME_PALETTE,
+        );
+
+        let text_area = Rect::new(area.x + 1, TOAST_ROW, message.len(), 1);
+        TEXT.draw(message, text_area, Align::Left, TEXT_PALETTE);
+        self.toast = Some(toast);
+    }
+
+    fn hide_toast(&mut self) {
+        if let Some(toast) = self.toast.take() {
+            let area = toast.area();
+            TEXT.clear_rect(area);
+            clear_bg_area(POPUP_SCREEN_BLOCK, area);
+        }
+    }
+
+    pub fn render(&self, oam: &mut ShadowOam) {
+        self.instance.render(oam);
+    }
 
-        self.prev_input = input;
+    /// Draws the frame around the grid
+    pub fn render_background(&self) {
+        let board = &self.instance.board;
+        let word_length = board.word().len() as i16;
+        let layout = GridLayout::new(
+            board.word().len(),
+            board.guess_count(),
+            board.current_row(),
+            self.instance.keyboard.y_offset(),
+        );
+
+        let grid_width = word_length * (TILE_WIDTH + TILE_PADDING) - TILE_PADDING;
+        let left = (layout.x_offset - TILE_PADDING) / 8;
+        let right = (layout.x_offset + grid_width + TILE_PADDING + 7) / 8;
+        let bottom = (layout.bottom() + TILE_PADDING + 7) / 8;
+
+        draw_panel(
+            FRAME_SCREEN_BLOCK,
+            left as usize,
+            0,
+            (right - left) as usize,
+            bottom as usize,
+            FRAME_PALETTE,
+        );
     }
+}
 
-    pub fn render(&self) {
-        self.instance.render();
+/// A short message shown in a box over the grid
+struct Toast {
+    message: &'static str,
+    tick: u16,
+}
+
+impl Toast {
+    /// The box around the message, in tiles
+    fn area(&self) -> Rect {
+        let width = self.message.len() + 2;
+        Rect::new((Rect::SCREEN.width - width) / 2, TOAST_ROW - 1, width, 3)
     }
 }
 
+/// Everything the player picks on the splash screen before a game starts
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum State {
-    Completed,
-    Failed,
-    InProgress,
+pub struct Options {
+    pub difficulty: Difficulty,
+    pub word_length: usize,
+    pub guess_count: usize,
+    pub keyboard: Keyboard,
+}
+
+impl Options {
+    pub const DEFAULT: Self = Self {
+        difficulty: Difficulty::Normal,
+        word_length: 5,
+        guess_count: 6,
+        keyboard: Keyboard::Carousel,
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Keyboard {
+    /// A single row of letters that scrolls past the cursor
+    Carousel,
+    /// Three rows of small keys laid out like a QWERTY keyboard
+    Qwerty,
+}
+
+impl Keyboard {
+    /// The top of the keyboard, which is also the bottom of the space the guess grid can use
+    const fn y_offset(self) -> i16 {
+        match self {
+            Self::Carousel => KEYBOARD_Y_OFFSET,
+            // Leave room for the selected key, which is drawn bigger than the others
+            Self::Qwerty => QWERTY_Y_OFFSET - KEY_OVERHANG,
+        }
+    }
 }
 
 struct Instance {
-    word: WordBuffer,
-    guesses: ArrayVec<WordBuffer, TILE_ROW_COUNT>,
-    letter_states: [LetterMatch; 27],
-    finished_guessing: bool,
+    board: Board,
+    keyboard: Keyboard,
     keyboard_anim_offset: i16,
     cursor: u8,
+    /// Whether the carousel cursor passes over letters known to be absent
+    skip_eliminated: bool,
+    /// The row and column of the selected key on the QWERTY keyboard
+    qwerty_cursor: (usize, usize),
+    rejection: Option<RejectionAnimation>,
+    reveal: Option<RevealAnimation>,
 }
 
 impl Instance {
-    fn new(word: WordBuffer) -> Self {
+    fn new(word: WordBuffer, options: Options) -> Self {
         Self {
-            word,
-            guesses: {
-                let mut guesses = ArrayVec::new();
-                guesses.push(WordBuffer::EMPTY);
-                guesses
-            },
-            letter_states: [LetterMatch::Grey; 27],
+            board: Board::new(word, options.guess_count, options.difficulty),
+            keyboard: options.keyboard,
             keyboard_anim_offset: 0,
-            finished_guessing: false,
             cursor: 0,
+            skip_eliminated: false,
+            qwerty_cursor: (0, 0),
+            rejection: None,
+            reveal: None,
         }
     }
 
-    pub fn state(&self) -> State {
-        let last_submitted_guess = if self.guesses.len() > 1 {
-            let last_guess_index = if self.finished_guessing {
-                self.guesses.len() - 1
-            } else {
-                self.guesses.len() - 2
-            };
+    fn selected_key(&self) -> u8 {
+        match self.keyboard {
+            Keyboard::Carousel => b'A' + self.cursor,
+            Keyboard::Qwerty => {
+                let (row, col) = self.qwerty_cursor;
+                QWERTY_ROWS[row][col]
+            }
+        }
+    }
 
-            self.guesses.nth(last_guess_index)
-        } else {
-            None
-        };
+    /// The popup goes up at the end of the game, once the last guess has been revealed
+    fn is_popup_shown(&self) -> bool {
+        self.board.state() != State::InProgress && self.reveal.is_none()
+    }
 
-        let word_guessed =
-            last_submitted_guess.map_or(false, |guess| guess.as_slice() == self.word.as_slice());
+    /// Applies the input, and plays a sound for whatever it ended up doing
+    fn input(&mut self, input: Input, sound: &mut SoundPlayer) {
+        let cursors = (self.cursor, self.qwerty_cursor);
+        let guess = *self.board.current_guess();
+        let guesses_used = self.board.guesses_used();
+
+        // Only typing, deleting or submitting dismisses the last rejection, so
+        // the player can move around the keyboard while they read why it was
+        let keeps_rejection = !matches!(input, Input::Char | Input::Delete | Input::Submit);
+        let previous_rejection = self.rejection.take();
+        self.apply_input(input);
+
+        if self.rejection.is_some() {
+            sound.play_over(sfx::INVALID);
+        } else if self.board.guesses_used() > guesses_used {
+            // Reveal the score of the guess a tile at a time
+            self.reveal = Some(RevealAnimation {
+                row: guesses_used,
+                tick: 0,
+            });
+
+            let score = self.board.score(guesses_used).unwrap();
+            // Whatever's left of the last guess's reveal gets cut off
+            sound.stop();
+            for m in &score[..self.board.word().len()] {
+                sound.queue(match m {
+                    LetterMatch::Green => sfx::REVEAL_GREEN,
+                    LetterMatch::Yellow => sfx::REVEAL_YELLOW,
+                    _ => sfx::REVEAL_BLACK,
+                });
+            }
 
-        if word_guessed {
-            State::Completed
-        } else if self.finished_guessing {
-            State::Failed
-        } else {
-            State::InProgress
+            match self.board.state() {
+                State::Completed => sound.queue(sfx::WIN),
+                State::Failed => sound.queue(sfx::LOSS),
+                State::InProgress => {}
+            }
+        } else if *self.board.current_guess() != guess {
+            if letters_typed(self.board.current_guess()) < letters_typed(&guess) {
+                sound.play_over(sfx::DELETE);
+            } else {
+                sound.play_over(sfx::LETTER);
+            }
+        } else if (self.cursor, self.qwerty_cursor) != cursors {
+            sound.play_over(sfx::CLICK);
+        }
+
+        if keeps_rejection {
+            self.rejection = previous_rejection;
+        }
+    }
+
+    /// Moves the cursor within the current guess, which works the same
+    /// way whichever keyboard is in use
+    fn move_guess_cursor(&mut self, delta: isize, sound: &mut SoundPlayer) {
+        let cursor = self.board.cursor();
+        self.board.move_cursor(delta);
+        if self.board.cursor() != cursor {
+            sound.play_over(sfx::CLICK);
         }
     }
 
-    fn input(&mut self, input: Input) {
+    fn apply_input(&mut self, input: Input) {
+        if self.keyboard == Keyboard::Qwerty {
+            self.qwerty_input(input);
+            return;
+        }
+
         match input {
             Input::Char => {
-                let current_guess = self.guesses.last_mut().unwrap();
-                if current_guess.is_full() {
-                    return;
+                let letter = AsciiChar(self.selected_key());
+                self.board.push_letter(letter);
+            }
+            Input::CursorUp | Input::CursorDown => {}
+            Input::CursorLeft => self.step_cursor(-1, self.skip_eliminated),
+            Input::CursorRight => self.step_cursor(1, self.skip_eliminated),
+            Input::JumpLeft => self.step_cursor(-1, true),
+            Input::JumpRight => self.step_cursor(1, true),
+            Input::ToggleSkipEliminated => {
+                self.skip_eliminated = !self.skip_eliminated;
+                self.settle_cursor();
+            }
+            Input::Delete => {
+                self.board.pop_letter();
+            }
+            Input::Submit => {
+                if let Err(rejection) = self.board.submit() {
+                    self.rejection = Some(RejectionAnimation { rejection, tick: 0 });
                 }
 
-                let letter = AsciiChar(b'A' + self.cursor);
-                current_guess.push(letter);
+                self.settle_cursor();
             }
-            Input::CursorLeft => {
-                if self.cursor > 0 {
-                    self.cursor -= 1;
-                    self.keyboard_anim_offset = -(TILE_WIDTH + TILE_PADDING);
-                }
+        }
+    }
+
+    fn is_eliminated(&self, cursor: u8) -> bool {
+        self.board.letter_state(AsciiChar(b'A' + cursor)) == LetterMatch::Black
+    }
+
+    /// Moves the carousel cursor one letter in `direction`, or to the next letter
+    /// in that direction that isn't known to be absent. The cursor stays put if
+    /// there's nowhere to go.
+    fn step_cursor(&mut self, direction: i8, skip_eliminated: bool) {
+        let mut target = self.cursor;
+        loop {
+            target = match target.checked_add_signed(direction) {
+                Some(target) if target < 26 => target,
+                _ => return,
+            };
+
+            if !skip_eliminated || !self.is_eliminated(target) {
+                break;
             }
-            Input::CursorRight => {
-                if self.cursor < 25 {
-                    self.cursor += 1;
-                    self.keyboard_anim_offset = TILE_WIDTH + TILE_PADDING;
-                }
+        }
+
+        // Add to the offset, rather than resetting it, so the
+        // keyboard picks up from wherever it was in the animation
+        let distance = target as i16 - self.cursor as i16;
+        self.keyboard_anim_offset += distance * (TILE_WIDTH + TILE_PADDING);
+        self.cursor = target;
+    }
+
+    /// Moves the cursor off of a letter that has just been eliminated,
+    /// when eliminated letters are being skipped
+    fn settle_cursor(&mut self) {
+        if self.skip_eliminated && self.is_eliminated(self.cursor) {
+            self.step_cursor(1, true);
+        }
+
+        if self.skip_eliminated && self.is_eliminated(self.cursor) {
+            self.step_cursor(-1, true);
+        }
+    }
+
+    /// The QWERTY keyboard has its own enter and delete keys, and uses
+    /// up and down to move between rows rather than to submit a guess.
+    fn qwerty_input(&mut self, input: Input) {
+        let (row, col) = &mut self.qwerty_cursor;
+        match input {
+            Input::Char => match self.selected_key() {
+                ENTER_KEY => self.apply_input(Input::Submit),
+                DELETE_KEY => self.apply_input(Input::Delete),
+                letter => self.board.push_letter(AsciiChar(letter)),
+            },
+            // The shortcuts only make sense for the carousel
+            Input::JumpLeft | Input::JumpRight | Input::ToggleSkipEliminated => {}
+            Input::CursorLeft => *col = col.saturating_sub(1),
+            Input::CursorRight => *col = (*col + 1).min(QWERTY_ROWS[*row].len() - 1),
+            Input::CursorUp => {
+                *row = row.saturating_sub(1);
+                *col = (*col).min(QWERTY_ROWS[*row].len() - 1);
+            }
+            Input::CursorDown => {
+                *row = (*row + 1).min(QWERTY_ROWS.len() - 1);
+                *col = (*col).min(QWERTY_ROWS[*row].len() - 1);
             }
             Input::Delete => {
-                let current_guess = self.guesses.last_mut().unwrap();
-                current_guess.pop();
+                self.board.pop_letter();
             }
             Input::Submit => {
-                let current_guess = self.guesses.last_mut().unwrap();
-                if !current_guess.is_full() {
-                    return;
+                if let Err(rejection) = self.board.submit() {
+                    self.rejection = Some(RejectionAnimation { rejection, tick: 0 });
                 }
+            }
+        }
+    }
 
-                // Check if the guess is valid or not
-                if !dictionary::is_valid_guess(&current_guess) {
-                    current_guess.clear();
-                    return;
-                }
+    /// Draws the finish screen, which goes up once the last guess has been revealed
+    fn render_popup(&self) {
+        const POPUP_Y_OFFSET: i16 = (SCREEN_HEIGHT - 3 * TILE_WIDTH) / 2;
 
-                // Update the keyboard colors
-                for (i, c) in current_guess.as_slice().iter().enumerate() {
-                    if self.word.as_slice()[i] == *c {
-                        self.letter_states[c.letter_index() as usize]
-                            .maybe_upgrade(LetterMatch::Green);
-                    } else if self.word.as_slice().contains(c) {
-                        self.letter_states[c.letter_index() as usize]
-                            .maybe_upgrade(LetterMatch::Yellow);
-                    } else {
-                        self.letter_states[c.letter_index() as usize] = LetterMatch::Black;
-                    }
-                }
+        let board = &self.board;
+        let popup_columns = board.word().len() as i16 + 2;
+        let popup_x_offset = (SCREEN_WIDTH - popup_columns * TILE_WIDTH) / 2;
 
-                // Add a new guess if the current one is full
-                self.finished_guessing = self.guesses.try_push(WordBuffer::EMPTY).is_err();
-            }
+        let palette = if board.state() == State::Completed {
+            POPUP_WIN_PALETTE
+        } else {
+            POPUP_LOSE_PALETTE
+        };
+
+        // Everything lines up with the 16x16 sprites, which are 2x2 tiles on a background
+        let x = (popup_x_offset / 8) as usize;
+        let y = (POPUP_Y_OFFSET / 8) as usize;
+        draw_panel(
+            POPUP_SCREEN_BLOCK,
+            x,
+            y,
+            2 * popup_columns as usize,
+            6,
+            palette,
+        );
+
+        for (i, letter) in board.word().as_slice().iter().enumerate() {
+            let tile = letter.tile_index();
+            draw_bg_tile(POPUP_SCREEN_BLOCK, x + 2 + 2 * i, y + 2, tile, palette);
         }
     }
 
-    fn render(&self) {
+    fn render(&self, oam: &mut ShadowOam) {
         fn draw_guessed_tile(
             char: AsciiChar,
             row: usize,
             col: usize,
             palette: u16,
-            allocator: &mut ObjAttrAllocator,
+            affine: Option<u16>,
+            layout: &GridLayout,
+            oam: &mut ShadowOam,
         ) {
-            let x = ROW_OFFSET + (col as i16) * (TILE_WIDTH + TILE_PADDING);
-            let y = TILE_PADDING + (row as i16) * (TILE_WIDTH + TILE_PADDING);
+            let Some((x, y)) = layout.position(row, col) else {
+                return;
+            };
 
-            let obj = ObjAttr::new()
+            let mut obj = ObjAttr::new()
                 .size(TileSize::SIZE_16X16)
                 .tile(char.tile_index())
                 .palette(palette)
+                .priority(GRID_PRIORITY)
                 .x(x)
                 .y(y);
 
-            allocator.allocate_and_write(obj);
-        }
-
-        let mut attr_allocator = ObjAttrAllocator::new();
+            if let Some(index) = affine {
+                obj = obj.affine(index);
+            }
 
-        // If the game is over, draw the finish screen
-        let state = self.state();
-        if state != State::InProgress {
-            const POPUP_ROWS: [[u16; 7]; 3] = [
-                [32, 33, 33, 33, 33, 33, 34],
-                [40, 00, 00, 00, 00, 00, 42],
-                [48, 49, 49, 49, 49, 49, 50],
-            ];
+            oam.push(obj);
+        }
 
-            const POPUP_WIDTH: i16 = 7 * TILE_WIDTH;
-            const POPUP_X_OFFSET: i16 = (SCREEN_WIDTH - POPUP_WIDTH) / 2;
-            const POPUP_Y_OFFSET: i16 = (SCREEN_HEIGHT - 3 * TILE_WIDTH) / 2;
+        oam.clear();
+        let board = &self.board;
+        let word_length = board.word().len();
+        let mut layout = GridLayout::new(
+            word_length,
+            board.guess_count(),
+            board.current_row(),
+            self.keyboard.y_offset(),
+        );
 
-            let palette = if state == State::Completed {
-                POPUP_WIN_PALETTE
-            } else {
-                POPUP_LOSE_PALETTE
+        // Explain why the last guess was rejected. This is drawn first so that
+        // it ends up on top of the grid.
+        if let Some(animation) = &self.rejection {
+            layout.shake = animation.shake_offset();
+
+            let mut missing_hint = *b"MUST USE  ";
+            let (text, last_palette) = match animation.rejection {
+                Rejection::Incomplete => ("TOO SHORT", BLACK_PALETTE),
+                Rejection::NotInDictionary => ("NOT IN LIST", BLACK_PALETTE),
+                Rejection::MissingGreen(letter) => {
+                    missing_hint[9] = letter.0;
+                    (core::str::from_utf8(&missing_hint).unwrap(), GREEN_PALETTE)
+                }
+                Rejection::MissingYellow(letter) => {
+                    missing_hint[9] = letter.0;
+                    (core::str::from_utf8(&missing_hint).unwrap(), YELLOW_PALETTE)
+                }
+                Rejection::GameOver => ("GAME OVER", BLACK_PALETTE),
             };
 
-            for (r, row) in POPUP_ROWS.iter().enumerate() {
-                for (c, tile) in row.iter().enumerate() {
-                    let x = POPUP_X_OFFSET + (c as i16) * TILE_WIDTH;
-                    let y = POPUP_Y_OFFSET + (r as i16) * TILE_WIDTH;
-
-                    let obj = if *tile == 0 {
-                        let letter = self.word.as_slice()[c - 1].tile_index();
-                        ObjAttr::new()
-                            .size(TileSize::SIZE_16X16)
-                            .tile(letter)
-                            .palette(palette)
-                            .x(x)
-                            .y(y)
+            let last = text.len() as u16 - 1;
+            draw_text(
+                text,
+                REJECTION_MESSAGE_Y_OFFSET,
+                |i| {
+                    if i == last {
+                        last_palette
                     } else {
-                        ObjAttr::new()
-                            .size(TileSize::SIZE_16X16)
-                            .tile(*tile * 4 + 1)
-                            .palette(palette)
-                            .x(x)
-                            .y(y)
-                    };
-
-                    attr_allocator.allocate_and_write(obj);
-                }
-            }
+                        BLACK_PALETTE
+                    }
+                },
+                oam,
+            );
         }
 
-        // Draw the main grid
-        for (row, word) in self.guesses.iter().enumerate() {
+        let state = board.state();
+
+        // Draw the main grid, picking out the cell at the cursor while the guess is being typed
+        let active_cell =
+            (state == State::InProgress).then(|| (board.current_row(), board.cursor()));
+        for (row, word) in board.guesses().enumerate() {
+            let score = board.score(row);
+            let reveal = self.reveal.as_ref().filter(|reveal| reveal.row == row);
             for (col, char) in word.as_slice().iter().enumerate() {
-                let palette_index = if *char == AsciiChar::NULL
-                    || (row == self.guesses.len() - 1 && !self.finished_guessing)
-                {
-                    BLACK_PALETTE
-                } else if self.word.as_slice()[col] == *char {
-                    GREEN_PALETTE
-                } else if self.word.as_slice().contains(char) {
-                    YELLOW_PALETTE
-                } else {
-                    GREY_PALETTE
+                // Tiles that are being turned over get a matrix each, which squashes
+                // them down to nothing and back as they go
+                let mut affine = None;
+                let mut revealed = true;
+                if let Some(reveal) = reveal {
+                    let height = math::cos(reveal.angle(col)).abs();
+                    oam.set_affine(col, AffineMatrix::scale(math::ONE, height));
+                    affine = Some(col as u16);
+                    revealed = reveal.is_revealed(col);
+                }
+
+                let palette_index = match score.filter(|_| revealed).map(|score| score[col]) {
+                    Some(LetterMatch::Green) => GREEN_PALETTE,
+                    Some(LetterMatch::Yellow) => YELLOW_PALETTE,
+                    Some(_) => GREY_PALETTE,
+                    None if active_cell == Some((row, col)) => CURSOR_PALETTE,
+                    None => BLACK_PALETTE,
                 };
 
-                draw_guessed_tile(*char, row, col, palette_index, &mut attr_allocator);
+                draw_guessed_tile(*char, row, col, palette_index, affine, &layout, oam);
             }
         }
 
-        let unused_guess_count = self.guesses.capacity() - self.guesses.len();
-        for i in 0..unused_guess_count {
-            for j in 0..5 {
-                draw_guessed_tile(
-                    AsciiChar::NULL,
-                    i + self.guesses.len(),
-                    j,
-                    BLACK_PALETTE,
-                    &mut attr_allocator,
-                );
+        let first_unused_row = board.current_row() + 1;
+        for i in first_unused_row..board.guess_count() {
+            for j in 0..word_length {
+                draw_guessed_tile(AsciiChar::NULL, i, j, BLACK_PALETTE, None, &layout, oam);
             }
         }
 
+        match self.keyboard {
+            Keyboard::Carousel => self.render_carousel(oam),
+            Keyboard::Qwerty => self.render_qwerty(oam),
+        }
+    }
+
+    fn render_carousel(&self, oam: &mut ShadowOam) {
         // Render the keyboard. The cursor is always in the middle,
         // so we need to adjust the position of the tiles so whatever
         // index the cursor is at is always in the middle.
@@ -373,8 +1050,8 @@ impl Instance {
         for i in 0..26 {
             let char = AsciiChar(b'A' + i as u8);
             let x = base_x_offset + (i as i16 - self.cursor as i16) * (TILE_WIDTH + TILE_PADDING);
-            let y = SCREEN_HEIGHT - TILE_WIDTH - 12;
-            let palette_index = self.letter_states[i].palette_index();
+            let y = KEYBOARD_Y_OFFSET;
+            let palette_index = letter_palette(self.board.letter_state(char));
             let tile_index = if x < -TILE_WIDTH || x >= SCREEN_WIDTH {
                 NULL_TILE * 4 + 1
             } else {
@@ -388,72 +1065,361 @@ impl Instance {
                 .x(x)
                 .y(y);
 
-            attr_allocator.allocate_and_write(obj);
+            oam.push(obj);
         }
 
-        // Draw the cursor
+        // Draw the cursor, which is lit up while eliminated letters are skipped
         let cursor_x = SCREEN_WIDTH / 2 - TILE_WIDTH / 2;
         let cursor_y = SCREEN_HEIGHT - TILE_PADDING - 6;
+        let cursor_palette = if self.skip_eliminated {
+            GREEN_PALETTE
+        } else {
+            BLACK_PALETTE
+        };
+
         let obj = ObjAttr::new()
             .size(TileSize::SIZE_16X16)
             .tile(27 * 4 + 1)
-            .palette(BLACK_PALETTE)
+            .palette(cursor_palette)
             .x(cursor_x)
             .y(cursor_y);
 
-        attr_allocator.allocate_and_write(obj);
+        oam.push(obj);
+    }
+
+    fn render_qwerty(&self, oam: &mut ShadowOam) {
+        fn key_tile(key: u8) -> u16 {
+            let index = match key {
+                ENTER_KEY => 26,
+                DELETE_KEY => 27,
+                letter => AsciiChar(letter).letter_index(),
+            };
+
+            KEY_TILE_OFFSET + index
+        }
+
+        fn key_palette(key: u8, board: &Board) -> u16 {
+            match key {
+                ENTER_KEY | DELETE_KEY => GREY_PALETTE,
+                letter => letter_palette(board.letter_state(AsciiChar(letter))),
+            }
+        }
+
+        // The selected key is drawn first so that it's on top of its neighbours.
+        // Letters are blown up to a full size tile, while enter and delete are
+        // lit up instead.
+        let (selected_row, selected_col) = self.qwerty_cursor;
+        let (x, y) = qwerty_key_position(selected_row, selected_col);
+        let key = self.selected_key();
+        let obj = match key {
+            ENTER_KEY | DELETE_KEY => ObjAttr::new()
+                .size(TileSize::SIZE_8X8)
+                .tile(key_tile(key))
+                .palette(POPUP_WIN_PALETTE)
+                .x(x)
+                .y(y),
+            letter => ObjAttr::new()
+                .size(TileSize::SIZE_16X16)
+                .tile(AsciiChar(letter).tile_index())
+                .palette(key_palette(key, &self.board))
+                .x(x - KEY_OVERHANG)
+                .y(y - KEY_OVERHANG),
+        };
+
+        oam.push(obj);
+
+        for (row, keys) in QWERTY_ROWS.iter().enumerate() {
+            for (col, key) in keys.iter().enumerate() {
+                if (row, col) == self.qwerty_cursor {
+                    continue;
+                }
+
+                let (x, y) = qwerty_key_position(row, col);
+                let obj = ObjAttr::new()
+                    .size(TileSize::SIZE_8X8)
+                    .tile(key_tile(*key))
+                    .palette(key_palette(*key, &self.board))
+                    .x(x)
+                    .y(y);
+
+                oam.push(obj);
+            }
+        }
     }
 }
 
-#[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum LetterMatch {
-    Grey = 0,
-    Yellow = 1,
-    Green = 2,
-    Black = 3,
+fn letters_typed(guess: &WordBuffer) -> usize {
+    guess
+        .as_slice()
+        .iter()
+        .filter(|&&c| c != AsciiChar::NULL)
+        .count()
+}
+
+/// The top left corner of a key on the QWERTY keyboard, with each row centered on the screen
+fn qwerty_key_position(row: usize, col: usize) -> (i16, i16) {
+    let row_width = QWERTY_ROWS[row].len() as i16 * KEY_PITCH - (KEY_PITCH - KEY_WIDTH);
+    let x = (SCREEN_WIDTH - row_width) / 2 + (col as i16) * KEY_PITCH;
+    let y = QWERTY_Y_OFFSET + (row as i16) * KEY_PITCH;
+    (x, y)
+}
+
+fn letter_palette(state: LetterMatch) -> u16 {
+    match state {
+        LetterMatch::Grey => GREY_PALETTE,
+        LetterMatch::Yellow => YELLOW_PALETTE,
+        LetterMatch::Green => GREEN_PALETTE,
+        LetterMatch::Black => BLACK_PALETTE,
+    }
+}
+
+/// The x position of the first of `len` padded tiles centered horizontally on the screen
+const fn centered_row_offset(len: i16) -> i16 {
+    let row_width = (len * TILE_WIDTH) + (TILE_PADDING * (len - 1));
+    (SCREEN_WIDTH - row_width) / 2
 }
 
-impl LetterMatch {
-    fn maybe_upgrade(&mut self, new_state: Self) {
-        if (*self as u8) < (new_state as u8) {
-            *self = new_state;
+/// Draws a row of letter tiles, centered horizontally on the screen. Spaces are left blank.
+fn draw_text(text: &str, y_offset: i16, palette: impl Fn(u16) -> u16, oam: &mut ShadowOam) {
+    let x_left = centered_row_offset(text.len() as i16);
+    for (i, letter) in text.chars().enumerate() {
+        if letter == ' ' {
+            continue;
         }
+
+        let x = x_left + (i as i16) * (TILE_WIDTH + TILE_PADDING);
+        let y = y_offset;
+
+        let obj = ObjAttr::new()
+            .size(TileSize::SIZE_16X16)
+            .tile(AsciiChar::from_u8(letter as u8).tile_index())
+            .palette(palette(i as u16))
+            .x(x)
+            .y(y);
+
+        oam.push(obj);
     }
+}
 
-    fn palette_index(&self) -> u16 {
-        match self {
-            Self::Grey => GREY_PALETTE,
-            Self::Yellow => YELLOW_PALETTE,
-            Self::Green => GREEN_PALETTE,
-            Self::Black => BLACK_PALETTE,
+/// Empties the backgrounds, which, unlike sprites, stay put until they're cleared
+pub fn clear_backgrounds() {
+    TEXT.clear();
+    for block in [POPUP_SCREEN_BLOCK, FRAME_SCREEN_BLOCK] {
+        let area = Rect::new(0, 0, SCREEN_BLOCK_WIDTH, SCREEN_BLOCK_WIDTH);
+        clear_bg_area(block, area);
+    }
+}
+
+fn clear_bg_area(block: usize, area: Rect) {
+    let map = screen_block(block);
+    for y in area.y..area.y + area.height {
+        for x in area.x..area.x + area.width {
+            map.index(x, y).write(TextEntry::new());
+        }
+    }
+}
+
+/// Draws a 16x16 sprite's tiles on a background, with its top left corner at tile `(x, y)`
+fn draw_bg_tile(block: usize, x: usize, y: usize, tile: u16, palette: u16) {
+    let map = screen_block(block);
+    for i in 0..4 {
+        let entry = TextEntry::new().tile(tile + i as u16).palette(palette);
+        map.index(x + i % 2, y + i / 2).write(entry);
+    }
+}
+
+/// Draws a box with a border on a background, in tiles. The corners and edges come
+/// from the quarters of the popup's sprites, so the box can be any size.
+fn draw_panel(block: usize, x: usize, y: usize, width: usize, height: usize, palette: u16) {
+    // The sprite, and which quarter of it, for the left, middle and right of each row
+    const TOP: [(u16, u16); 3] = [(32, 0), (33, 0), (34, 1)];
+    const MIDDLE: [(u16, u16); 3] = [(40, 0), (41, 0), (42, 1)];
+    const BOTTOM: [(u16, u16); 3] = [(48, 2), (49, 2), (50, 3)];
+
+    let map = screen_block(block);
+    for row in 0..height {
+        let pieces = if row == 0 {
+            TOP
+        } else if row == height - 1 {
+            BOTTOM
+        } else {
+            MIDDLE
+        };
+
+        for col in 0..width {
+            let (sprite, quarter) = if col == 0 {
+                pieces[0]
+            } else if col == width - 1 {
+                pieces[2]
+            } else {
+                pieces[1]
+            };
+
+            let entry = TextEntry::new()
+                .tile(sprite * 4 + 1 + quarter)
+                .palette(palette);
+            map.index(x + col, y + row).write(entry);
+        }
+    }
+}
+
+/// Draws the decimal digits of a number from left to right, starting at `x`
+fn draw_number(value: u16, x: i16, y: i16, palette: u16, oam: &mut ShadowOam) {
+    let mut digits = [0u8; 5];
+    for (i, digit) in format_number(value, &mut digits).bytes().enumerate() {
+        let obj = ObjAttr::new()
+            .size(TileSize::SIZE_16X16)
+            .tile(AsciiChar::from_u8(digit).tile_index())
+            .palette(palette)
+            .x(x + (i as i16) * TILE_WIDTH)
+            .y(y);
+
+        oam.push(obj);
+    }
+}
+
+/// Writes out the decimal digits of `value` at the end of `buffer`
+fn format_number(value: u16, buffer: &mut [u8; 5]) -> &str {
+    let mut start = buffer.len();
+    let mut remaining = value;
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (remaining % 10) as u8;
+        remaining /= 10;
+
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    core::str::from_utf8(&buffer[start..]).unwrap()
+}
+
+/*
+   Where the tiles of the guess grid go on screen. Rows are squeezed
+   closer together when there are more guesses than fit above the
+   keyboard, and if they still don't fit, the grid scrolls so that
+   the current guess is always in view.
+*/
+struct GridLayout {
+    x_offset: i16,
+    row_pitch: i16,
+    first_row: usize,
+    visible_rows: usize,
+    current_row: usize,
+    /// How far the current row is nudged sideways when it's shaking
+    shake: i16,
+}
+
+impl GridLayout {
+    fn new(word_length: usize, row_count: usize, current_row: usize, bottom: i16) -> Self {
+        let grid_height = bottom - TILE_PADDING - GRID_Y_OFFSET;
+        let space_between_rows = grid_height - TILE_WIDTH;
+        let row_pitch = match row_count {
+            0 | 1 => TILE_WIDTH + TILE_PADDING,
+            rows => (space_between_rows / (rows as i16 - 1))
+                .clamp(GRID_MIN_ROW_PITCH, TILE_WIDTH + TILE_PADDING),
+        };
+
+        let visible_rows = ((space_between_rows / row_pitch) as usize + 1).min(row_count);
+        let first_row = (current_row + 1)
+            .saturating_sub(visible_rows)
+            .min(row_count - visible_rows);
+
+        Self {
+            x_offset: centered_row_offset(word_length as i16),
+            row_pitch,
+            first_row,
+            visible_rows,
+            current_row,
+            shake: 0,
         }
     }
+
+    /// The bottom of the lowest row on screen
+    fn bottom(&self) -> i16 {
+        GRID_Y_OFFSET + (self.visible_rows as i16 - 1) * self.row_pitch + TILE_WIDTH
+    }
+
+    fn position(&self, row: usize, col: usize) -> Option<(i16, i16)> {
+        if row < self.first_row || row >= self.first_row + self.visible_rows {
+            return None;
+        }
+
+        let shake = if row == self.current_row {
+            self.shake
+        } else {
+            0
+        };
+        let x = self.x_offset + shake + (col as i16) * (TILE_WIDTH + TILE_PADDING);
+        let y = GRID_Y_OFFSET + ((row - self.first_row) as i16) * self.row_pitch;
+        Some((x, y))
+    }
+}
+
+/// Shakes the current row, and shows why it was rejected for a little while
+struct RejectionAnimation {
+    rejection: Rejection,
+    tick: u16,
+}
+
+impl RejectionAnimation {
+    fn shake_offset(&self) -> i16 {
+        let step = (self.tick / REJECTION_SHAKE_TICKS_PER_STEP) as usize;
+        REJECTION_SHAKE_STEPS.get(step).copied().unwrap_or(0)
+    }
+}
+
+/// Turns the tiles of a guess over one column at a time, showing their
+/// colors from the moment they're edge on
+struct RevealAnimation {
+    row: usize,
+    tick: u16,
+}
+
+impl RevealAnimation {
+    /// How far over a column's tile has turned, from flat to a half turn
+    fn angle(&self, col: usize) -> u8 {
+        let start = col as u16 * FLIP_STAGGER_TICKS;
+        let progress = self.tick.saturating_sub(start).min(FLIP_TICKS);
+        (progress as u32 * 2 * QUARTER_TURN as u32 / FLIP_TICKS as u32) as u8
+    }
+
+    fn is_revealed(&self, col: usize) -> bool {
+        self.angle(col) >= QUARTER_TURN
+    }
+
+    fn is_finished(&self, word_length: usize) -> bool {
+        self.tick >= (word_length as u16 - 1) * FLIP_STAGGER_TICKS + FLIP_TICKS
+    }
 }
 
 enum Input {
     Char,
     CursorLeft,
     CursorRight,
+    CursorUp,
+    CursorDown,
+    /// Skip to the next letter on the left that isn't known to be absent
+    JumpLeft,
+    /// Skip to the next letter on the right that isn't known to be absent
+    JumpRight,
+    ToggleSkipEliminated,
     Delete,
     Submit,
 }
 
-struct ObjAttrAllocator(u16);
-
-impl ObjAttrAllocator {
-    fn new() -> Self {
-        Self(0)
-    }
-
-    fn allocate(&mut self) -> usize {
-        let index = self.0;
-        self.0 += 1;
-        index as usize
-    }
+/// Where a character's 16x16 sprite starts in the sprite tiles
+trait TileIndex {
+    fn tile_index(self) -> u16;
+}
 
-    fn allocate_and_write(&mut self, attr: ObjAttr) {
-        let index = self.allocate();
-        OBJ_ATTRS.index(index).write(attr);
+impl TileIndex for AsciiChar {
+    fn tile_index(self) -> u16 {
+        if self.0 >= b'0' && self.0 <= b'9' {
+            (DIGIT_SPRITE_OFFSET + (self.0 - b'0') as u16) * 4 + 1
+        } else {
+            self.letter_index() * 4 + 1
+        }
     }
 }
@@ -1,110 +1,118 @@
+/// The raw contents of `KEYINPUT`, where a cleared bit means the key is held
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct KeyInput(pub u16);
 
 impl KeyInput {
     #[inline]
-    pub const fn is_some(self) -> bool {
-        self.0 != 0
-    }
-
-    #[inline]
-    pub const fn a(self) -> bool {
-        (self.0 & (1 << 0)) == 0
-    }
-
-    #[inline]
-    pub const fn a_once(self, old: Self) -> bool {
-        self.a() && !old.a()
-    }
-
-    #[inline]
-    pub const fn b(self) -> bool {
-        (self.0 & (1 << 1)) == 0
-    }
-
-    #[inline]
-    pub const fn b_once(self, old: Self) -> bool {
-        self.b() && !old.b()
-    }
-
-    #[inline]
-    pub const fn select(self) -> bool {
-        (self.0 & (1 << 2)) == 0
-    }
-
-    #[inline]
-    pub const fn select_once(self, old: Self) -> bool {
-        self.select() && !old.select()
-    }
-
-    #[inline]
-    pub const fn start(self) -> bool {
-        (self.0 & (1 << 3)) == 0
-    }
-
-    #[inline]
-    pub const fn start_once(self, old: Self) -> bool {
-        self.start() && !old.start()
+    pub const fn held_keys(self) -> Key {
+        Key::from_bits_truncate(!self.0)
     }
+}
 
-    #[inline]
-    pub const fn right(self) -> bool {
-        (self.0 & (1 << 4)) == 0
+bitflags::bitflags! {
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Key: u16 {
+        const A = 1 << 0;
+        const B = 1 << 1;
+        const SELECT = 1 << 2;
+        const START = 1 << 3;
+        const RIGHT = 1 << 4;
+        const LEFT = 1 << 5;
+        const UP = 1 << 6;
+        const DOWN = 1 << 7;
+        const R = 1 << 8;
+        const L = 1 << 9;
     }
+}
 
-    #[inline]
-    pub const fn right_once(self, old: Self) -> bool {
-        self.right() && !old.right()
-    }
+const KEY_COUNT: usize = 10;
+
+/// How many frames a key has to be held before it starts repeating
+pub const DEFAULT_REPEAT_DELAY: u16 = 16;
+/// How many frames there are between each repeat once a key is repeating
+pub const DEFAULT_REPEAT_RATE: u16 = 4;
+
+/*
+   Keeps track of the keys from one frame to the next, so that presses
+   and releases can be told apart from keys that are simply held down.
+   There should only be one of these, updated once at the start of every
+   frame, and shared by everything that reads input during that frame.
+
+   Every query takes a set of keys, and is true if any of them match.
+*/
+pub struct Buttons {
+    held: Key,
+    previous: Key,
+    /// How many frames in a row each key has been held for, by bit index
+    held_frames: [u16; KEY_COUNT],
+    repeat_delay: u16,
+    repeat_rate: u16,
+}
 
-    #[inline]
-    pub const fn left(self) -> bool {
-        (self.0 & (1 << 5)) == 0
+impl Buttons {
+    /// Held keys count as pressed again after `repeat_delay` frames, and
+    /// then every `repeat_rate` frames, the way a keyboard does.
+    pub const fn new(repeat_delay: u16, repeat_rate: u16) -> Self {
+        assert!(repeat_rate > 0);
+        Self {
+            held: Key::empty(),
+            previous: Key::empty(),
+            held_frames: [0; KEY_COUNT],
+            repeat_delay,
+            repeat_rate,
+        }
     }
 
-    #[inline]
-    pub const fn left_once(self, old: Self) -> bool {
-        self.left() && !old.left()
-    }
+    pub fn update(&mut self, input: KeyInput) {
+        self.previous = self.held;
+        self.held = input.held_keys();
 
-    #[inline]
-    pub const fn up(self) -> bool {
-        (self.0 & (1 << 6)) == 0
+        for (i, frames) in self.held_frames.iter_mut().enumerate() {
+            *frames = if self.held.bits() & (1 << i) != 0 {
+                frames.saturating_add(1)
+            } else {
+                0
+            };
+        }
     }
 
-    #[inline]
-    pub const fn up_once(self, old: Self) -> bool {
-        self.up() && !old.up()
+    pub fn held_keys(&self) -> Key {
+        self.held
     }
 
-    #[inline]
-    pub const fn down(self) -> bool {
-        (self.0 & (1 << 7)) == 0
+    pub fn held(&self, keys: Key) -> bool {
+        self.held.intersects(keys)
     }
 
-    #[inline]
-    pub const fn down_once(self, old: Self) -> bool {
-        self.down() && !old.down()
+    /// Keys that went down this frame
+    pub fn pressed(&self, keys: Key) -> bool {
+        self.held.difference(self.previous).intersects(keys)
     }
 
-    #[inline]
-    pub const fn r(self) -> bool {
-        (self.0 & (1 << 8)) == 0
+    /// Keys that came up this frame
+    pub fn released(&self, keys: Key) -> bool {
+        self.previous.difference(self.held).intersects(keys)
     }
 
-    #[inline]
-    pub const fn r_once(self, old: Self) -> bool {
-        self.r() && !old.r()
+    /// Keys that were pressed this frame, or have been held long enough to repeat
+    pub fn repeated(&self, keys: Key) -> bool {
+        keys.iter().any(|key| {
+            let frames = self.held_frames(key);
+            frames == 1
+                || (frames > self.repeat_delay
+                    && (frames - 1 - self.repeat_delay).is_multiple_of(self.repeat_rate))
+        })
     }
 
-    #[inline]
-    pub const fn l(self) -> bool {
-        (self.0 & (1 << 9)) == 0
+    /// How many frames in a row a single key has been held for, including this one
+    pub fn held_frames(&self, key: Key) -> u16 {
+        let index = key.bits().trailing_zeros() as usize;
+        self.held_frames.get(index).copied().unwrap_or(0)
     }
 
-    #[inline]
-    pub const fn l_once(self, old: Self) -> bool {
-        self.l() && !old.l()
+    pub fn repeat_rate(&self) -> u16 {
+        self.repeat_rate
     }
 }
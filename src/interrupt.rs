@@ -0,0 +1,121 @@
+use crate::{
+    mmio::{BIOS_IF, DISPSTAT, IE, IF, IME, IRQ_HANDLER},
+    video::DisplayStatus,
+};
+
+bitflags::bitflags! {
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Interrupt: u16 {
+        const VBLANK = 1 << 0;
+        const HBLANK = 1 << 1;
+        const VCOUNT = 1 << 2;
+        const TIMER0 = 1 << 3;
+        const TIMER1 = 1 << 4;
+        const TIMER2 = 1 << 5;
+        const TIMER3 = 1 << 6;
+        const SERIAL = 1 << 7;
+        const DMA0 = 1 << 8;
+        const DMA1 = 1 << 9;
+        const DMA2 = 1 << 10;
+        const DMA3 = 1 << 11;
+        const KEYPAD = 1 << 12;
+        const GAMEPAK = 1 << 13;
+    }
+}
+
+const INTERRUPT_COUNT: usize = 14;
+
+/*
+   Handlers run in IRQ mode, on the small stack the BIOS sets aside
+   for it, while the main program is paused. They should do as little
+   as possible, and leave anything heavy for the main loop to pick up.
+*/
+pub type Handler = fn();
+
+static mut HANDLERS: [Option<Handler>; INTERRUPT_COUNT] = [None; INTERRUPT_COUNT];
+
+/// Points the BIOS at our dispatcher and turns on the master interrupt
+/// switch. Individual interrupts still need to be `enable`d.
+pub fn init() {
+    IME.write(false);
+    unsafe { IRQ_HANDLER.write(Some(dispatch)) };
+    IME.write(true);
+}
+
+/// Calls `handler` whenever `interrupt` fires, replacing any previous
+/// handler. The interrupt is acknowledged either way, so `None` is fine
+/// for interrupts that are only used to wake the CPU up.
+pub fn set_handler(interrupt: Interrupt, handler: Option<Handler>) {
+    let index = interrupt.bits().trailing_zeros() as usize;
+    assert!(interrupt.bits().count_ones() == 1 && index < INTERRUPT_COUNT);
+
+    // The dispatcher mustn't see the table half written
+    without_interrupts(|| unsafe {
+        let handlers = &raw mut HANDLERS;
+        (*handlers)[index] = handler;
+    });
+}
+
+/// Enables interrupts in `IE`. The display interrupts are also switched on
+/// in `DISPSTAT`, but anything else has to be configured to raise its
+/// interrupt where it's set up, eg. in the timer or DMA control registers.
+pub fn enable(interrupts: Interrupt) {
+    DISPSTAT.write(DISPSTAT.read() | display_status(interrupts));
+    IE.write(IE.read() | interrupts);
+}
+
+pub fn disable(interrupts: Interrupt) {
+    IE.write(IE.read().difference(interrupts));
+    DISPSTAT.write(DISPSTAT.read().difference(display_status(interrupts)));
+}
+
+/// Runs `f` with the master interrupt switch off, restoring it afterwards
+pub fn without_interrupts<T>(f: impl FnOnce() -> T) -> T {
+    let enabled = IME.read();
+    IME.write(false);
+    let result = f();
+    IME.write(enabled);
+    result
+}
+
+fn display_status(interrupts: Interrupt) -> DisplayStatus {
+    let mut status = DisplayStatus::empty();
+    status.set(
+        DisplayStatus::VBLANK_IRQ,
+        interrupts.contains(Interrupt::VBLANK),
+    );
+    status.set(
+        DisplayStatus::HBLANK_IRQ,
+        interrupts.contains(Interrupt::HBLANK),
+    );
+    status.set(
+        DisplayStatus::VCOUNT_IRQ,
+        interrupts.contains(Interrupt::VCOUNT),
+    );
+    status
+}
+
+/*
+   The BIOS jumps here in ARM mode, having already saved the registers
+   the C ABI doesn't preserve. Pending interrupts are acknowledged both
+   in `IF` and in the BIOS' own copy of it, which `IntrWait` and friends
+   check to see whether the interrupt they're waiting on has happened.
+*/
+#[instruction_set(arm::a32)]
+extern "C" fn dispatch() {
+    let pending = IE.read() & IF.read();
+    IF.write(pending);
+    BIOS_IF.write(BIOS_IF.read() | pending);
+
+    let handlers = unsafe { HANDLERS };
+    for (index, handler) in handlers.iter().enumerate() {
+        if pending.bits() & (1 << index) == 0 {
+            continue;
+        }
+
+        if let Some(handler) = handler {
+            handler();
+        }
+    }
+}
@@ -1,9 +1,18 @@
 #![feature(naked_functions)]
 #![no_std]
 
+pub mod bios;
+pub mod direct_sound;
+pub mod dma;
 pub mod input;
+pub mod interrupt;
+pub mod math;
 pub mod mmio;
 pub mod rand;
+pub mod save;
+pub mod sound;
+pub mod text;
+pub mod timer;
 pub mod video;
 
 #[naked]
@@ -15,6 +24,23 @@ unsafe extern "C" fn _start() -> ! {
       "b 1f",
       ".space 0xE0",
       "1:",
+      // Zero .bss
+      "ldr r0, =__bss_start",
+      "ldr r1, =__bss_end",
+      "mov r2, #0",
+      "2:",
+      "cmp r0, r1",
+      "strlo r2, [r0], #4",
+      "blo 2b",
+      // Copy .data from ROM
+      "ldr r0, =__data_start",
+      "ldr r1, =__data_end",
+      "ldr r3, =__data_load",
+      "3:",
+      "cmp r0, r1",
+      "ldrlo r2, [r3], #4",
+      "strlo r2, [r0], #4",
+      "blo 3b",
       "ldr r12, =main",
       "bx r12",
       options(noreturn)
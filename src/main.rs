@@ -1,119 +1,313 @@
 #![no_std]
 #![no_main]
 
-use game::{Game, SplashScreen};
+use game::{
+    Game, Keyboard, Options, SplashScreen, StatsScreen, BG_CHAR_BLOCK, FONT_TILE_OFFSET, FRAME_BG,
+    FRAME_SCREEN_BLOCK, KEY_TILE_OFFSET, POPUP_BG, POPUP_SCREEN_BLOCK, TEXT_BG, TEXT_SCREEN_BLOCK,
+};
+use stats::Stats;
 use wordboy::{
-    input::KeyInput,
-    mmio::{DISPCNT, KEYINPUT, OBJ_ATTRS, OBJ_PALETTE, OBJ_TILE4},
+    direct_sound::{self, Channel},
+    input::{Buttons, Key, DEFAULT_REPEAT_DELAY, DEFAULT_REPEAT_RATE},
+    interrupt::{self, Interrupt},
+    mmio::{BG_CONTROL, BG_PALETTE, DISPCNT, KEYINPUT, OBJ_PALETTE, OBJ_TILE4},
     rand::PRNG,
-    video::{wait_vblank, Color, DisplayControl, ObjAttr, Tile4},
+    sound::{self, SoundPlayer},
+    video::{
+        char_block, wait_vblank, BackgroundControl, BlendLayers, Color, DisplayControl, Fade,
+        FadeColor, ShadowOam, Tile4, BLEND_MAX,
+    },
 };
+use wordboy_rules::board::State;
 
-mod dictionary;
 mod game;
-mod utils;
+mod sfx;
+mod stats;
+
+/// How many frames it takes to fade between the start screen and a game
+const FADE_TICKS: u16 = 16;
 
 #[no_mangle]
 pub extern "C" fn main() -> ! {
+    initialize_interrupts();
+    sound::init();
+    direct_sound::init();
     initialize_display();
     initialize_palette();
     intiialize_sprites();
+    initialize_keys();
+    initialize_backgrounds();
 
     let mut rng = PRNG::seeded();
+    let mut options = Options::DEFAULT;
+    let mut stats = Stats::load();
+    let mut oam = ShadowOam::new();
+    let mut buttons = Buttons::new(DEFAULT_REPEAT_DELAY, DEFAULT_REPEAT_RATE);
+    let mut sound = SoundPlayer::new();
 
     'restart: loop {
         wait_vblank();
-
-        // Nuke the display
-        for attr in OBJ_ATTRS.iter() {
-            attr.write(ObjAttr::new());
-        }
+        clear_display(&mut oam);
 
         // Start screen
         {
-            let mut prev_input = KeyInput(0);
-            let mut splash_screen = SplashScreen::new();
+            let mut splash_screen = SplashScreen::new(options);
+            splash_screen.render_text();
+            direct_sound::play(Channel::A, sfx::splash_music(), true);
+            let mut fade = Fade::fade_in(FadeColor::Black, FADE_TICKS);
             loop {
                 // Poke the RNG to increase our amount of perceived randomness
                 _ = rng.next();
 
                 wait_vblank();
+                oam.commit();
+                if !fade.is_finished() {
+                    fade.tick();
+                }
                 splash_screen.update();
-                splash_screen.render();
+                splash_screen.render(&mut oam);
 
-                let input = KEYINPUT.read();
-                if input.start_once(prev_input) || input.a_once(prev_input) {
-                    break;
+                buttons.update(KEYINPUT.read());
+                if buttons.pressed(Key::SELECT) {
+                    splash_screen.toggle_difficulty();
+                }
+
+                if buttons.pressed(Key::LEFT) {
+                    splash_screen.adjust_word_length(-1);
+                }
+
+                if buttons.pressed(Key::RIGHT) {
+                    splash_screen.adjust_word_length(1);
+                }
+
+                if buttons.pressed(Key::DOWN) {
+                    splash_screen.adjust_guess_count(-1);
+                }
+
+                if buttons.pressed(Key::UP) {
+                    splash_screen.adjust_guess_count(1);
+                }
+
+                if buttons.pressed(Key::L) {
+                    splash_screen.toggle_keyboard();
+                }
+
+                if buttons.pressed(Key::R) {
+                    direct_sound::play(Channel::B, sfx::whoosh(), false);
+                    // The other screens aren't faded, so don't leave them dimmed
+                    fade.finish();
+                    show_stats(
+                        stats,
+                        splash_screen.options().guess_count,
+                        &mut buttons,
+                        &mut oam,
+                    );
+                    splash_screen.render_text();
+                    continue;
+                }
+
+                if buttons.pressed(Key::B) {
+                    fade.finish();
+                    show_help(splash_screen.options().keyboard, &mut buttons, &mut oam);
+                    splash_screen.render_text();
+                    continue;
                 }
 
-                prev_input = input;
+                if buttons.pressed(Key::START | Key::A) {
+                    options = splash_screen.options();
+                    direct_sound::stop(Channel::A);
+                    direct_sound::play(Channel::B, sfx::start(), false);
+                    fade_out(fade.level(), &mut sound);
+                    break;
+                }
             }
         }
 
         // Main game loop
         'new_game: loop {
-            // Nuke the display
-            for attr in OBJ_ATTRS.iter() {
-                attr.write(ObjAttr::new());
-            }
+            wait_vblank();
+            clear_display(&mut oam);
 
-            let mut game = Game::new(rng.next());
+            let mut game = Game::new(rng.next(), options);
+            game.render_background();
             let mut game_ticks = 0u16;
-            let mut prev_input = KeyInput(0);
+            let mut fade = Fade::fade_in(FadeColor::Black, FADE_TICKS);
 
             'game_tick: loop {
                 wait_vblank();
+                oam.commit();
+                // Once it's done, the fade in leaves the blend registers to the game
+                if !fade.is_finished() {
+                    fade.tick();
+                }
+                sound.update();
 
                 game_ticks = game_ticks.wrapping_add(1);
-                let current_input = KEYINPUT.read();
+                buttons.update(KEYINPUT.read());
 
-                if current_input.is_some() {
+                let held_keys = buttons.held_keys();
+                if !held_keys.is_empty() {
                     // Poke the RNG to increase our amount of perceived randomness
-                    rng.donate(current_input.0.wrapping_mul(game_ticks));
+                    rng.donate(held_keys.bits().wrapping_mul(game_ticks));
                 }
 
-                if current_input.start_once(prev_input) {
+                if buttons.pressed(Key::START) {
+                    // Walking away from a game that's underway counts as losing it,
+                    // so it can't be used to protect the streak
+                    if game.guesses_used() > 0 {
+                        stats.record_loss();
+                        stats.save();
+                    }
+
+                    fade_out(fade.level(), &mut sound);
                     continue 'restart;
                 }
 
-                game.update(current_input);
-                game.render();
-
-                prev_input = current_input;
+                game.update(&buttons, &mut sound);
+                game.render(&mut oam);
 
                 match game.state() {
-                    game::State::InProgress => {
+                    State::InProgress => {
                         continue 'game_tick;
                     }
-                    game::State::Completed => {
-                        // Noop, just let the user see that they've won
+                    State::Completed => {
+                        stats.record_win(game.guesses_used());
+                        stats.save();
                     }
-                    game::State::Failed => {
-                        // TODO: Draw the word for feedback to the user
+                    State::Failed => {
+                        stats.record_loss();
+                        stats.save();
                     }
                 }
 
                 // Wait for the user to restart the game or head back to the start screen
                 loop {
                     wait_vblank();
-                    let current_input = KEYINPUT.read();
-                    if current_input.a_once(prev_input) {
+                    oam.commit();
+                    sound.update();
+
+                    buttons.update(KEYINPUT.read());
+                    game.animate(&buttons);
+                    game.render(&mut oam);
+
+                    // Let the last guess be revealed and the popup come up first
+                    if !game.is_popup_settled() {
+                        continue;
+                    }
+
+                    if buttons.pressed(Key::A) {
+                        fade_out_popup(&game, &mut oam, &mut sound);
                         continue 'new_game;
                     }
 
-                    if current_input.start_once(prev_input) || current_input.b_once(prev_input) {
+                    if buttons.pressed(Key::START | Key::B) {
+                        fade_out_popup(&game, &mut oam, &mut sound);
                         continue 'restart;
                     }
-
-                    prev_input = current_input;
                 }
             }
         }
     }
 }
 
+/// Fades the screen out to black from `from` sixteenths of the way there, keeping
+/// the sound effects going while it does
+fn fade_out(from: u16, sound: &mut SoundPlayer) {
+    let mut fade = Fade::new(
+        FadeColor::Black,
+        BlendLayers::all(),
+        from,
+        BLEND_MAX,
+        FADE_TICKS,
+    );
+    while !fade.is_finished() {
+        wait_vblank();
+        fade.tick();
+        sound.update();
+    }
+}
+
+/*
+   The board has already been dimmed behind the popup, but the blend
+   registers only have the one level, so fading the whole screen out
+   from there would darken the popup all at once. Instead, the board is
+   faded the rest of the way out and cleared away, and then the popup is
+   faded out on its own.
+*/
+fn fade_out_popup(game: &Game, oam: &mut ShadowOam, sound: &mut SoundPlayer) {
+    let remaining = BLEND_MAX - game.dim_level();
+    let mut fade = game.fade_out_board(FADE_TICKS * remaining / BLEND_MAX);
+    while !fade.is_finished() {
+        wait_vblank();
+        fade.tick();
+        sound.update();
+    }
+
+    wait_vblank();
+    game.clear_board(oam);
+    fade_out(0, sound);
+}
+
+/// Shows the stats screen until the player backs out of it
+fn show_stats(stats: Stats, guess_count: usize, buttons: &mut Buttons, oam: &mut ShadowOam) {
+    let stats_screen = StatsScreen::new(stats, guess_count);
+
+    wait_vblank();
+    clear_display(oam);
+    stats_screen.render_text();
+
+    loop {
+        wait_vblank();
+        oam.commit();
+        stats_screen.render(oam);
+
+        buttons.update(KEYINPUT.read());
+        if buttons.pressed(Key::B | Key::R | Key::START) {
+            wait_vblank();
+            clear_display(oam);
+            return;
+        }
+    }
+}
+
+/// Shows how to play until the player backs out of it
+fn show_help(keyboard: Keyboard, buttons: &mut Buttons, oam: &mut ShadowOam) {
+    wait_vblank();
+    clear_display(oam);
+    game::render_help(keyboard);
+
+    loop {
+        wait_vblank();
+        oam.commit();
+
+        buttons.update(KEYINPUT.read());
+        if buttons.pressed(Key::B | Key::START) {
+            wait_vblank();
+            clear_display(oam);
+            return;
+        }
+    }
+}
+
+fn clear_display(oam: &mut ShadowOam) {
+    oam.clear();
+    oam.commit();
+    game::clear_backgrounds();
+}
+
+fn initialize_interrupts() {
+    interrupt::init();
+    interrupt::enable(Interrupt::VBLANK);
+}
+
 fn initialize_display() {
-    DISPCNT.write(DisplayControl::ENABLE_OBJ | DisplayControl::LINEAR_OBJ_TILE_DATA);
+    DISPCNT.write(
+        DisplayControl::ENABLE_OBJ
+            | DisplayControl::LINEAR_OBJ_TILE_DATA
+            | DisplayControl::ENABLE_BG0
+            | DisplayControl::ENABLE_BG1
+            | DisplayControl::ENABLE_BG2,
+    );
 }
 
 fn initialize_palette() {
@@ -157,6 +351,16 @@ fn initialize_palette() {
     OBJ_PALETTE.index(16 * 6 + 2).write(Color::rgb(13, 15, 15));
     OBJ_PALETTE.index(16 * 6 + 3).write(Color::rgb(13, 15, 15));
     OBJ_PALETTE.index(16 * 6 + 4).write(Color::rgb(8, 10, 10));
+
+    // Palette Bank 7 : Grid frame
+    OBJ_PALETTE.index(16 * 7 + 2).write(Color::rgb(3, 3, 4));
+    OBJ_PALETTE.index(16 * 7 + 4).write(Color::rgb(6, 6, 7));
+
+    // Palette Bank 11 : The empty cell at the guess cursor
+    OBJ_PALETTE.index(16 * 11 + 1).write(Color::WHITE);
+    OBJ_PALETTE.index(16 * 11 + 2).write(Color::rgb(4, 6, 10));
+    OBJ_PALETTE.index(16 * 11 + 3).write(Color::rgb(6, 6, 6));
+    OBJ_PALETTE.index(16 * 11 + 4).write(Color::rgb(22, 24, 28));
 }
 
 fn intiialize_sprites() {
@@ -204,3 +408,80 @@ fn intiialize_sprites() {
         }
     }
 }
+
+fn initialize_keys() {
+    const KEYS: &[u8] = include_bytes!(env!("KEYS_BIN"));
+
+    // The small keys are plain 8x8 tiles, so they can be copied over as they are
+    for (i, tile) in plain_tiles(KEYS).enumerate() {
+        OBJ_TILE4.index(KEY_TILE_OFFSET as usize + i).write(tile);
+    }
+}
+
+/// Reads 8x8 tiles that are laid out one after the other
+fn plain_tiles(data: &'static [u8]) -> impl Iterator<Item = Tile4> {
+    data.chunks_exact(32).map(|data| {
+        let mut tile: Tile4 = [0; 8];
+        for (word, bytes) in tile.iter_mut().zip(data.chunks_exact(4)) {
+            *word = u32::from_le_bytes(bytes.try_into().unwrap());
+        }
+
+        tile
+    })
+}
+
+fn initialize_backgrounds() {
+    // The backgrounds share the sprites' tiles and palettes. Tile 0 is left
+    // empty, so that an empty map entry is transparent.
+    let tiles = char_block(BG_CHAR_BLOCK);
+    tiles.index(0).write([0; 8]);
+    for i in 1..=256 {
+        tiles.index(i).write(OBJ_TILE4.index(i).read());
+    }
+
+    const FONT: &[u8] = include_bytes!(env!("FONT_BIN"));
+    for (i, tile) in plain_tiles(FONT).enumerate() {
+        tiles.index(FONT_TILE_OFFSET as usize + i).write(tile);
+    }
+
+    // The first color of the background palette is the backdrop, which stays black
+    BG_PALETTE.index(0).write(Color::BLACK);
+    for i in 1..BG_PALETTE.len() {
+        BG_PALETTE.index(i).write(OBJ_PALETTE.index(i).read());
+    }
+
+    // Palette Bank 8 : Text
+    BG_PALETTE.index(16 * 8 + 1).write(Color::WHITE);
+    BG_PALETTE.index(16 * 8 + 2).write(Color::rgb(4, 4, 5));
+
+    // Palette Bank 9 : Green text
+    BG_PALETTE.index(16 * 9 + 1).write(Color::rgb(9, 20, 16));
+    BG_PALETTE.index(16 * 9 + 2).write(Color::rgb(4, 4, 5));
+
+    // Palette Bank 10 : Grey text
+    BG_PALETTE.index(16 * 10 + 1).write(Color::rgb(13, 15, 15));
+    BG_PALETTE.index(16 * 10 + 2).write(Color::rgb(4, 4, 5));
+
+    BG_CONTROL.index(TEXT_BG).write(
+        BackgroundControl::new()
+            .priority(0)
+            .char_block(BG_CHAR_BLOCK as u16)
+            .screen_block(TEXT_SCREEN_BLOCK as u16),
+    );
+
+    BG_CONTROL.index(POPUP_BG).write(
+        BackgroundControl::new()
+            .priority(0)
+            .char_block(BG_CHAR_BLOCK as u16)
+            .screen_block(POPUP_SCREEN_BLOCK as u16),
+    );
+
+    BG_CONTROL.index(FRAME_BG).write(
+        BackgroundControl::new()
+            .priority(3)
+            .char_block(BG_CHAR_BLOCK as u16)
+            .screen_block(FRAME_SCREEN_BLOCK as u16),
+    );
+
+    game::clear_backgrounds();
+}
@@ -0,0 +1,16 @@
+/// 1.0 in the 8.8 fixed point numbers used for sines and affine transforms
+pub const ONE: i16 = 1 << 8;
+
+/// A quarter of a turn, with angles in 256ths of a turn so that they wrap around on their own
+pub const QUARTER_TURN: u8 = 64;
+
+/// The sine of every angle, generated by `build.rs`
+static SINE: [i16; 256] = include!(env!("SINE_TABLE"));
+
+pub fn sin(angle: u8) -> i16 {
+    SINE[angle as usize]
+}
+
+pub fn cos(angle: u8) -> i16 {
+    SINE[angle.wrapping_add(QUARTER_TURN) as usize]
+}
@@ -1,17 +1,52 @@
-use voladdress::{Safe, VolAddress, VolBlock, VolSeries};
+use voladdress::{Safe, Unsafe, VolAddress, VolBlock, VolGrid2dStrided, VolSeries};
 
 use crate::{
+    dma::DmaControl,
     input::KeyInput,
+    interrupt::Interrupt,
+    save::SRAM_SIZE,
+    sound::{
+        Envelope, MixControl, Noise, PsgControl, SoundStatus, Sweep, Tone, WaveControl, WaveVolume,
+    },
+    timer::TimerControl,
     video::{
-        Color, DisplayControl, ObjAttr, ObjAttr0, ObjAttr1, ObjAttr2, Tile4, Tile8,
-        OBJ_TILE_MEM_WORD_COUNT,
+        BackgroundControl, BlendAlpha, BlendControl, Color, DisplayControl, DisplayStatus, ObjAttr,
+        ObjAttr0, ObjAttr1, ObjAttr2, TextEntry, Tile4, Tile8, OBJ_TILE_MEM_WORD_COUNT,
+        SCREEN_BLOCK_WIDTH, SIZE_OF_SCREEN_BLOCK,
     },
 };
 
 pub const DISPCNT: VolAddress<DisplayControl, Safe, Safe> = unsafe { VolAddress::new(0x0400_0000) };
+pub const DISPSTAT: VolAddress<DisplayStatus, Safe, Safe> = unsafe { VolAddress::new(0x0400_0004) };
 pub const KEYINPUT: VolAddress<KeyInput, Safe, ()> = unsafe { VolAddress::new(0x0400_0130) };
 
+pub const BG_CONTROL: VolBlock<BackgroundControl, Safe, Safe, 4> =
+    unsafe { VolBlock::new(0x0400_0008) };
+pub const BG_HOFS: VolSeries<u16, (), Safe, 4, 4> = unsafe { VolSeries::new(0x0400_0010) };
+pub const BG_VOFS: VolSeries<u16, (), Safe, 4, 4> = unsafe { VolSeries::new(0x0400_0012) };
+
+pub const BLDCNT: VolAddress<BlendControl, Safe, Safe> = unsafe { VolAddress::new(0x0400_0050) };
+pub const BLDALPHA: VolAddress<BlendAlpha, Safe, Safe> = unsafe { VolAddress::new(0x0400_0052) };
+/// The brightness coefficient, from 0 to `video::BLEND_MAX`
+pub const BLDY: VolAddress<u16, (), Safe> = unsafe { VolAddress::new(0x0400_0054) };
+
 pub const BACKDROP: VolAddress<Color, Safe, Safe> = unsafe { VolAddress::new(0x0500_0000) };
+/// The first color of the first bank is the backdrop, rather than transparent
+pub const BG_PALETTE: VolBlock<Color, Safe, Safe, 256> = unsafe { VolBlock::new(0x0500_0000) };
+
+/// The 64KB of VRAM that tiled backgrounds can use, split into four char blocks
+/// of tiles. See `video::char_block`.
+pub const BG_TILE4: VolBlock<Tile4, Safe, Safe, 2048> = unsafe { VolBlock::new(0x0600_0000) };
+/// The same VRAM, split into 32 screen blocks of maps. See `video::screen_block`.
+pub const SCREEN_BLOCKS: VolGrid2dStrided<
+    TextEntry,
+    Safe,
+    Safe,
+    SCREEN_BLOCK_WIDTH,
+    SCREEN_BLOCK_WIDTH,
+    32,
+    SIZE_OF_SCREEN_BLOCK,
+> = unsafe { VolGrid2dStrided::new(0x0600_0000) };
 
 pub const OBJ_PALETTE: VolBlock<Color, Safe, Safe, 256> = unsafe { VolBlock::new(0x0500_0200) };
 pub const OBJ_TILES: VolBlock<u32, Safe, Safe, OBJ_TILE_MEM_WORD_COUNT> =
@@ -31,3 +66,63 @@ pub const OBJ_ATTRS: VolSeries<ObjAttr, Safe, Safe, 128, 8> =
     unsafe { VolSeries::new(0x0700_0000) };
 
 pub const REG_VCOUNT: VolAddress<u16, Safe, ()> = unsafe { VolAddress::new(0x0400_0006) };
+
+pub const SRAM: VolBlock<u8, Safe, Safe, SRAM_SIZE> = unsafe { VolBlock::new(0x0E00_0000) };
+
+pub const DMA1_SRC: VolAddress<usize, (), Unsafe> = unsafe { VolAddress::new(0x0400_00BC) };
+pub const DMA1_DEST: VolAddress<usize, (), Unsafe> = unsafe { VolAddress::new(0x0400_00C0) };
+pub const DMA1_COUNT: VolAddress<u16, (), Unsafe> = unsafe { VolAddress::new(0x0400_00C4) };
+pub const DMA1_CONTROL: VolAddress<DmaControl, Safe, Unsafe> =
+    unsafe { VolAddress::new(0x0400_00C6) };
+
+pub const DMA2_SRC: VolAddress<usize, (), Unsafe> = unsafe { VolAddress::new(0x0400_00C8) };
+pub const DMA2_DEST: VolAddress<usize, (), Unsafe> = unsafe { VolAddress::new(0x0400_00CC) };
+pub const DMA2_COUNT: VolAddress<u16, (), Unsafe> = unsafe { VolAddress::new(0x0400_00D0) };
+pub const DMA2_CONTROL: VolAddress<DmaControl, Safe, Unsafe> =
+    unsafe { VolAddress::new(0x0400_00D2) };
+
+pub const DMA3_SRC: VolAddress<usize, (), Unsafe> = unsafe { VolAddress::new(0x0400_00D4) };
+pub const DMA3_DEST: VolAddress<usize, (), Unsafe> = unsafe { VolAddress::new(0x0400_00D8) };
+pub const DMA3_COUNT: VolAddress<u16, (), Unsafe> = unsafe { VolAddress::new(0x0400_00DC) };
+pub const DMA3_CONTROL: VolAddress<DmaControl, Safe, Unsafe> =
+    unsafe { VolAddress::new(0x0400_00DE) };
+
+pub const IE: VolAddress<Interrupt, Safe, Safe> = unsafe { VolAddress::new(0x0400_0200) };
+pub const IF: VolAddress<Interrupt, Safe, Safe> = unsafe { VolAddress::new(0x0400_0202) };
+pub const IME: VolAddress<bool, Safe, Safe> = unsafe { VolAddress::new(0x0400_0208) };
+
+/// The BIOS' copy of the acknowledged interrupts, which `IntrWait` checks
+pub const BIOS_IF: VolAddress<Interrupt, Safe, Safe> = unsafe { VolAddress::new(0x0300_7FF8) };
+/// Where the BIOS jumps to, in ARM mode, when an interrupt fires
+pub const IRQ_HANDLER: VolAddress<Option<extern "C" fn()>, Safe, Unsafe> =
+    unsafe { VolAddress::new(0x0300_7FFC) };
+
+pub const SOUND1CNT_L: VolAddress<Sweep, Safe, Safe> = unsafe { VolAddress::new(0x0400_0060) };
+pub const SOUND1CNT_H: VolAddress<Envelope, Safe, Safe> = unsafe { VolAddress::new(0x0400_0062) };
+pub const SOUND1CNT_X: VolAddress<Tone, Safe, Safe> = unsafe { VolAddress::new(0x0400_0064) };
+pub const SOUND2CNT_L: VolAddress<Envelope, Safe, Safe> = unsafe { VolAddress::new(0x0400_0068) };
+pub const SOUND2CNT_H: VolAddress<Tone, Safe, Safe> = unsafe { VolAddress::new(0x0400_006C) };
+pub const SOUND3CNT_L: VolAddress<WaveControl, Safe, Safe> =
+    unsafe { VolAddress::new(0x0400_0070) };
+pub const SOUND3CNT_H: VolAddress<WaveVolume, Safe, Safe> = unsafe { VolAddress::new(0x0400_0072) };
+pub const SOUND3CNT_X: VolAddress<Tone, Safe, Safe> = unsafe { VolAddress::new(0x0400_0074) };
+pub const SOUND4CNT_L: VolAddress<Envelope, Safe, Safe> = unsafe { VolAddress::new(0x0400_0078) };
+pub const SOUND4CNT_H: VolAddress<Noise, Safe, Safe> = unsafe { VolAddress::new(0x0400_007C) };
+
+pub const SOUNDCNT_L: VolAddress<PsgControl, Safe, Safe> = unsafe { VolAddress::new(0x0400_0080) };
+pub const SOUNDCNT_H: VolAddress<MixControl, Safe, Safe> = unsafe { VolAddress::new(0x0400_0082) };
+pub const SOUNDCNT_X: VolAddress<SoundStatus, Safe, Safe> = unsafe { VolAddress::new(0x0400_0084) };
+
+/// The bank of wave RAM that the wave channel isn't playing
+pub const WAVE_RAM: VolBlock<u32, Safe, Safe, 4> = unsafe { VolBlock::new(0x0400_0090) };
+
+/// Direct Sound's sample queues, which are fed 4 samples at a time
+pub const FIFO_A: VolAddress<u32, (), Safe> = unsafe { VolAddress::new(0x0400_00A0) };
+pub const FIFO_B: VolAddress<u32, (), Safe> = unsafe { VolAddress::new(0x0400_00A4) };
+
+/// Writing the reload value takes effect the next time the timer starts or overflows,
+/// while reading gives the current count
+pub const TM0CNT_L: VolAddress<u16, Safe, Safe> = unsafe { VolAddress::new(0x0400_0100) };
+pub const TM0CNT_H: VolAddress<TimerControl, Safe, Safe> = unsafe { VolAddress::new(0x0400_0102) };
+pub const TM1CNT_L: VolAddress<u16, Safe, Safe> = unsafe { VolAddress::new(0x0400_0104) };
+pub const TM1CNT_H: VolAddress<TimerControl, Safe, Safe> = unsafe { VolAddress::new(0x0400_0106) };
@@ -0,0 +1,228 @@
+use core::marker::PhantomData;
+
+use crate::mmio::SRAM;
+
+pub const SRAM_SIZE: usize = 32 * 1024;
+
+/*
+    Emulators and flashcarts figure out what kind of save memory a cartridge
+    has by scanning the ROM for one of these ID strings. It needs to be word
+    aligned and padded with zeros, and must survive linking even though
+    nothing reads it, which the linker script takes care of.
+*/
+#[repr(C, align(4))]
+pub struct SaveTypeId([u8; 12]);
+
+#[used]
+#[no_mangle]
+#[link_section = ".rodata.save_type"]
+pub static SAVE_TYPE_ID: SaveTypeId = SaveTypeId(*b"SRAM_V113\0\0\0");
+
+/// SRAM sits on an 8-bit bus, so it has to be read one byte at a time
+pub fn read_bytes(offset: usize, buffer: &mut [u8]) {
+    for (i, byte) in buffer.iter_mut().enumerate() {
+        *byte = SRAM.index(offset + i).read();
+    }
+}
+
+/// SRAM sits on an 8-bit bus, so it has to be written one byte at a time
+pub fn write_bytes(offset: usize, buffer: &[u8]) {
+    for (i, byte) in buffer.iter().enumerate() {
+        SRAM.index(offset + i).write(*byte);
+    }
+}
+
+/// A type that can be persisted in save memory. The `MAGIC` identifies which
+/// kind of record a slot holds, and `VERSION` lets `decode` read records that
+/// were written by an older layout.
+pub trait Record: Sized {
+    const MAGIC: [u8; 4];
+    const VERSION: u16;
+
+    /// The largest number of bytes `encode` will ever write
+    const SIZE: usize;
+
+    fn encode(&self, writer: &mut Writer);
+    fn decode(version: u16, reader: &mut Reader) -> Option<Self>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoadError {
+    /// Nothing of this type has been saved yet
+    Empty,
+    /// The data doesn't match its checksum, ie. power was lost mid-write
+    Corrupt,
+    /// The record was written by a version `decode` doesn't understand
+    UnsupportedVersion(u16),
+}
+
+/*
+    A record is laid out in SRAM as:
+     * Magic (4 bytes)
+     * Version (2 bytes)
+     * Payload length (2 bytes)
+     * Payload checksum (2 bytes)
+     * Payload (length bytes)
+
+    The payload is written before the header, and the magic last of all, so
+    a write that's interrupted never leaves behind a header that vouches for
+    a half written payload.
+*/
+const HEADER_SIZE: usize = 10;
+
+pub struct Slot<R: Record> {
+    offset: usize,
+    _record: PhantomData<R>,
+}
+
+impl<R: Record> Slot<R> {
+    pub const CAPACITY: usize = HEADER_SIZE + R::SIZE;
+
+    pub const fn new(offset: usize) -> Self {
+        assert!(offset + HEADER_SIZE + R::SIZE <= SRAM_SIZE);
+        Self {
+            offset,
+            _record: PhantomData,
+        }
+    }
+
+    pub fn load(&self) -> Result<R, LoadError> {
+        let mut header = [0u8; HEADER_SIZE];
+        read_bytes(self.offset, &mut header);
+
+        if header[0..4] != R::MAGIC {
+            return Err(LoadError::Empty);
+        }
+
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        let length = u16::from_le_bytes([header[6], header[7]]) as usize;
+        let checksum = u16::from_le_bytes([header[8], header[9]]);
+
+        if length > R::SIZE {
+            return Err(LoadError::Corrupt);
+        }
+
+        let mut payload = Checksum::new();
+        for i in 0..length {
+            payload.update(SRAM.index(self.offset + HEADER_SIZE + i).read());
+        }
+
+        if payload.value() != checksum {
+            return Err(LoadError::Corrupt);
+        }
+
+        let mut reader = Reader {
+            offset: self.offset + HEADER_SIZE,
+            remaining: length,
+        };
+
+        R::decode(version, &mut reader).ok_or(LoadError::UnsupportedVersion(version))
+    }
+
+    pub fn store(&self, record: &R) {
+        // Invalidate the old record first in case we lose power part way through
+        write_bytes(self.offset, &[0; 4]);
+
+        let mut writer = Writer {
+            offset: self.offset + HEADER_SIZE,
+            length: 0,
+            checksum: Checksum::new(),
+        };
+
+        record.encode(&mut writer);
+
+        let length = writer.length as u16;
+        let checksum = writer.checksum.value();
+
+        write_bytes(self.offset + 4, &R::VERSION.to_le_bytes());
+        write_bytes(self.offset + 6, &length.to_le_bytes());
+        write_bytes(self.offset + 8, &checksum.to_le_bytes());
+        write_bytes(self.offset, &R::MAGIC);
+    }
+
+    pub fn clear(&self) {
+        write_bytes(self.offset, &[0; 4]);
+    }
+}
+
+/// Reads little-endian values out of a record's payload. Reading past the end
+/// of the payload yields zeros, so newer fields default to zero when decoding
+/// a record written before they existed.
+pub struct Reader {
+    offset: usize,
+    remaining: usize,
+}
+
+impl Reader {
+    pub fn u8(&mut self) -> u8 {
+        if self.remaining == 0 {
+            return 0;
+        }
+
+        let byte = SRAM.index(self.offset).read();
+        self.offset += 1;
+        self.remaining -= 1;
+        byte
+    }
+
+    pub fn u16(&mut self) -> u16 {
+        u16::from_le_bytes([self.u8(), self.u8()])
+    }
+
+    pub fn u32(&mut self) -> u32 {
+        u32::from_le_bytes([self.u8(), self.u8(), self.u8(), self.u8()])
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.u8() != 0
+    }
+}
+
+/// Writes little-endian values into a record's payload
+pub struct Writer {
+    offset: usize,
+    length: usize,
+    checksum: Checksum,
+}
+
+impl Writer {
+    pub fn u8(&mut self, value: u8) {
+        SRAM.index(self.offset + self.length).write(value);
+        self.checksum.update(value);
+        self.length += 1;
+    }
+
+    pub fn u16(&mut self, value: u16) {
+        for byte in value.to_le_bytes() {
+            self.u8(byte);
+        }
+    }
+
+    pub fn u32(&mut self, value: u32) {
+        for byte in value.to_le_bytes() {
+            self.u8(byte);
+        }
+    }
+
+    pub fn bool(&mut self, value: bool) {
+        self.u8(value as u8);
+    }
+}
+
+/// Fletcher-16, which is cheap to compute a byte at a time
+struct Checksum(u16, u16);
+
+impl Checksum {
+    fn new() -> Self {
+        Self(0, 0)
+    }
+
+    fn update(&mut self, byte: u8) {
+        self.0 = (self.0 + byte as u16) % 255;
+        self.1 = (self.1 + self.0) % 255;
+    }
+
+    fn value(&self) -> u16 {
+        (self.1 << 8) | self.0
+    }
+}
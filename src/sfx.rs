@@ -0,0 +1,115 @@
+use wordboy::{
+    direct_sound::Pcm,
+    include_pcm,
+    sound::{
+        square_rate, wave_rate, Duty, Envelope, Noise, Note, SoundEffect, Sweep, Tone, Voice,
+        WaveVolume,
+    },
+};
+
+/// How many frames apart each tile of a guess is revealed
+pub const REVEAL_DELAY: u8 = 10;
+/// How many frames apart the notes of the jingles are
+const JINGLE_DELAY: u8 = 8;
+
+/// A short blip on the second square channel, which fades out on its own
+const fn blip(delay: u8, hz: u32, duty: Duty) -> Note {
+    let envelope = Envelope::new().duty(duty).volume(10).step_time(1);
+    Note::new(
+        delay,
+        Voice::Square2(envelope, Tone::new().rate(square_rate(hz))),
+    )
+}
+
+/// A note of a jingle, with the melody on the first square channel
+const fn melody(delay: u8, hz: u32) -> Note {
+    let envelope = Envelope::new().duty(Duty::Half).volume(12).step_time(3);
+    Note::new(
+        delay,
+        Voice::Square1(Sweep::new(), envelope, Tone::new().rate(square_rate(hz))),
+    )
+}
+
+/// A note of a jingle, with the bass on the wave channel. The wave channel
+/// has no envelope, so these are timed instead.
+const fn bass(delay: u8, hz: u32) -> Note {
+    let volume = WaveVolume::HALF.length(192);
+    Note::new(
+        delay,
+        Voice::Wave(volume, Tone::new().rate(wave_rate(hz)).timed(true)),
+    )
+}
+
+/// Moving the cursor around the keyboard, or around the guess
+pub const CLICK: SoundEffect = &[Note::new(
+    0,
+    Voice::Noise(
+        Envelope::new().volume(6).step_time(1),
+        Noise::new().short(true).shift(2),
+    ),
+)];
+
+pub const LETTER: SoundEffect = &[blip(0, 880, Duty::Half)];
+
+pub const DELETE: SoundEffect = &[blip(0, 440, Duty::Half)];
+
+/// A low buzz that sweeps downwards, for guesses that aren't accepted
+pub const INVALID: SoundEffect = &[
+    Note::new(
+        0,
+        Voice::Square1(
+            Sweep::new().decrease(true).shift(3).step_time(2),
+            Envelope::new().duty(Duty::Quarter).volume(12).step_time(2),
+            Tone::new().rate(square_rate(196)),
+        ),
+    ),
+    Note::new(
+        JINGLE_DELAY,
+        Voice::Square1(
+            Sweep::new().decrease(true).shift(3).step_time(2),
+            Envelope::new().duty(Duty::Quarter).volume(12).step_time(2),
+            Tone::new().rate(square_rate(147)),
+        ),
+    ),
+];
+
+/// One tile of a guess being revealed. These are queued up one per tile,
+/// so each of them waits a little before playing.
+pub const REVEAL_GREEN: SoundEffect = &[blip(REVEAL_DELAY, 1047, Duty::Eighth)];
+pub const REVEAL_YELLOW: SoundEffect = &[blip(REVEAL_DELAY, 784, Duty::Eighth)];
+pub const REVEAL_BLACK: SoundEffect = &[blip(REVEAL_DELAY, 392, Duty::Eighth)];
+
+/// A rising arpeggio, C E G C
+pub const WIN: SoundEffect = &[
+    melody(REVEAL_DELAY, 523),
+    bass(0, 131),
+    melody(JINGLE_DELAY, 659),
+    melody(JINGLE_DELAY, 784),
+    melody(JINGLE_DELAY, 1047),
+    bass(0, 262),
+];
+
+/// A falling line, G E C, ending in a low C
+pub const LOSS: SoundEffect = &[
+    melody(REVEAL_DELAY, 392),
+    bass(0, 98),
+    melody(JINGLE_DELAY, 330),
+    melody(JINGLE_DELAY, 262),
+    melody(JINGLE_DELAY * 2, 131),
+    bass(0, 65),
+];
+
+/// The music on the start screen, which loops until a game starts
+pub fn splash_music() -> Pcm {
+    include_pcm!(env!("SPLASH_PCM"))
+}
+
+/// A chime for starting a game
+pub fn start() -> Pcm {
+    include_pcm!(env!("START_PCM"))
+}
+
+/// Opening the stats screen
+pub fn whoosh() -> Pcm {
+    include_pcm!(env!("WHOOSH_PCM"))
+}
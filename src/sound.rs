@@ -0,0 +1,558 @@
+use bitfrob::{u16_with_bit, u16_with_value};
+
+use crate::{
+    direct_sound,
+    mmio::{
+        SOUND1CNT_H, SOUND1CNT_L, SOUND1CNT_X, SOUND2CNT_H, SOUND2CNT_L, SOUND3CNT_H, SOUND3CNT_L,
+        SOUND3CNT_X, SOUND4CNT_H, SOUND4CNT_L, SOUNDCNT_H, SOUNDCNT_L, SOUNDCNT_X, WAVE_RAM,
+    },
+};
+
+bitflags::bitflags! {
+    /// The four PSG channels, as they're laid out in `SOUNDCNT_L` and `SOUNDCNT_X`
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Channels: u16 {
+        const SQUARE1 = 1 << 0;
+        const SQUARE2 = 1 << 1;
+        const WAVE = 1 << 2;
+        const NOISE = 1 << 3;
+    }
+}
+
+bitflags::bitflags! {
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct SoundStatus: u16 {
+        const SQUARE1_ON = 1 << 0;
+        const SQUARE2_ON = 1 << 1;
+        const WAVE_ON = 1 << 2;
+        const NOISE_ON = 1 << 3;
+        /// Nothing else in the sound hardware can be written while this is off
+        const MASTER_ENABLE = 1 << 7;
+    }
+}
+
+/// `SOUNDCNT_L`: the volume of the PSG channels, and which speakers they play out of
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct PsgControl(pub u16);
+impl PsgControl {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// From 0 to 7
+    #[inline]
+    pub const fn volume(self, left: u16, right: u16) -> Self {
+        Self(u16_with_value(
+            4,
+            6,
+            u16_with_value(0, 2, self.0, right),
+            left,
+        ))
+    }
+
+    #[inline]
+    pub const fn left(self, channels: Channels) -> Self {
+        Self(u16_with_value(12, 15, self.0, channels.bits()))
+    }
+
+    #[inline]
+    pub const fn right(self, channels: Channels) -> Self {
+        Self(u16_with_value(8, 11, self.0, channels.bits()))
+    }
+}
+
+/// `SOUNDCNT_H`: how the PSG channels are mixed with Direct Sound
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct MixControl(pub u16);
+impl MixControl {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn psg_volume(self, volume: PsgVolume) -> Self {
+        Self(u16_with_value(0, 1, self.0, volume as u16))
+    }
+
+    /// Plays a Direct Sound channel at full volume, rather than half
+    #[inline]
+    pub const fn direct_sound_full_volume(
+        self,
+        channel: direct_sound::Channel,
+        full: bool,
+    ) -> Self {
+        Self(u16_with_bit(2 + channel as u32, self.0, full))
+    }
+
+    #[inline]
+    pub const fn direct_sound_output(
+        self,
+        channel: direct_sound::Channel,
+        left: bool,
+        right: bool,
+    ) -> Self {
+        let shift = 4 * channel as u32;
+        Self(u16_with_bit(
+            9 + shift,
+            u16_with_bit(8 + shift, self.0, right),
+            left,
+        ))
+    }
+
+    /// Which timer, 0 or 1, clocks samples out of a Direct Sound channel's FIFO
+    #[inline]
+    pub const fn direct_sound_timer(self, channel: direct_sound::Channel, timer: u16) -> Self {
+        Self(u16_with_bit(10 + 4 * channel as u32, self.0, timer != 0))
+    }
+
+    /// Empties a Direct Sound channel's FIFO. This bit always reads back as zero.
+    #[inline]
+    pub const fn direct_sound_reset(self, channel: direct_sound::Channel) -> Self {
+        Self(u16_with_bit(11 + 4 * channel as u32, self.0, true))
+    }
+}
+
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PsgVolume {
+    Quarter = 0,
+    Half = 1,
+    Full = 2,
+}
+
+/// `SOUND1CNT_L`: the frequency sweep, which only the first square channel has
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct Sweep(pub u16);
+impl Sweep {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Each step changes the rate by `rate >> shift`
+    #[inline]
+    pub const fn shift(self, shift: u16) -> Self {
+        Self(u16_with_value(0, 2, self.0, shift))
+    }
+
+    #[inline]
+    pub const fn decrease(self, decrease: bool) -> Self {
+        Self(u16_with_bit(3, self.0, decrease))
+    }
+
+    /// How long each step lasts, in 128ths of a second. Zero turns the sweep off.
+    #[inline]
+    pub const fn step_time(self, time: u16) -> Self {
+        Self(u16_with_value(4, 6, self.0, time))
+    }
+}
+
+/// `SOUND1CNT_H`, `SOUND2CNT_L` and `SOUND4CNT_L`: the length and volume
+/// envelope of a note. The duty cycle only applies to the square channels.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct Envelope(pub u16);
+impl Envelope {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// The note lasts for `(64 - length) / 256` seconds, if it's timed
+    #[inline]
+    pub const fn length(self, length: u16) -> Self {
+        Self(u16_with_value(0, 5, self.0, length))
+    }
+
+    #[inline]
+    pub const fn duty(self, duty: Duty) -> Self {
+        Self(u16_with_value(6, 7, self.0, duty as u16))
+    }
+
+    /// How long each step of the envelope lasts, in 64ths of a second.
+    /// Zero holds the volume where it started.
+    #[inline]
+    pub const fn step_time(self, time: u16) -> Self {
+        Self(u16_with_value(8, 10, self.0, time))
+    }
+
+    #[inline]
+    pub const fn increase(self, increase: bool) -> Self {
+        Self(u16_with_bit(11, self.0, increase))
+    }
+
+    /// From 0 to 15
+    #[inline]
+    pub const fn volume(self, volume: u16) -> Self {
+        Self(u16_with_value(12, 15, self.0, volume))
+    }
+}
+
+/// How much of each cycle a square wave spends high
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Duty {
+    Eighth = 0,
+    Quarter = 1,
+    Half = 2,
+    ThreeQuarters = 3,
+}
+
+/// `SOUND1CNT_X`, `SOUND2CNT_H` and `SOUND3CNT_X`: the pitch of a note, and
+/// the bit that starts it playing
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct Tone(pub u16);
+impl Tone {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// See `square_rate` and `wave_rate`
+    #[inline]
+    pub const fn rate(self, rate: u16) -> Self {
+        Self(u16_with_value(0, 10, self.0, rate))
+    }
+
+    /// Stops the note once its length runs out, rather than playing until replaced
+    #[inline]
+    pub const fn timed(self, timed: bool) -> Self {
+        Self(u16_with_bit(14, self.0, timed))
+    }
+
+    #[inline]
+    pub const fn restart(self) -> Self {
+        Self(u16_with_bit(15, self.0, true))
+    }
+}
+
+/// The `Tone` rate that plays a square wave at `hz`
+pub const fn square_rate(hz: u32) -> u16 {
+    2048 - (131072 / hz) as u16
+}
+
+/// The `Tone` rate that plays the wave channel's 32 samples `hz` times a second
+pub const fn wave_rate(hz: u32) -> u16 {
+    2048 - (65536 / hz) as u16
+}
+
+/// `SOUND3CNT_L`: which bank of wave RAM is played
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct WaveControl(pub u16);
+impl WaveControl {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Plays both banks one after the other as a single 64 sample wave
+    #[inline]
+    pub const fn two_banks(self, two_banks: bool) -> Self {
+        Self(u16_with_bit(5, self.0, two_banks))
+    }
+
+    /// The bank that's played. The other one is the one that's visible in `WAVE_RAM`.
+    #[inline]
+    pub const fn bank(self, bank: u16) -> Self {
+        Self(u16_with_value(6, 6, self.0, bank))
+    }
+
+    #[inline]
+    pub const fn enabled(self, enabled: bool) -> Self {
+        Self(u16_with_bit(7, self.0, enabled))
+    }
+}
+
+/// `SOUND3CNT_H`: the length and volume of the wave channel
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct WaveVolume(pub u16);
+impl WaveVolume {
+    pub const MUTE: Self = Self::new().volume(0);
+    pub const FULL: Self = Self::new().volume(1);
+    pub const HALF: Self = Self::new().volume(2);
+    pub const QUARTER: Self = Self::new().volume(3);
+    pub const THREE_QUARTERS: Self = Self(1 << 15);
+
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// The note lasts for `(256 - length) / 256` seconds, if it's timed
+    #[inline]
+    pub const fn length(self, length: u16) -> Self {
+        Self(u16_with_value(0, 7, self.0, length))
+    }
+
+    #[inline]
+    const fn volume(self, volume: u16) -> Self {
+        Self(u16_with_value(13, 14, self.0, volume))
+    }
+}
+
+/// `SOUND4CNT_H`: the pitch and texture of the noise channel
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct Noise(pub u16);
+impl Noise {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// The noise is clocked at `524288 / ratio / 2^(shift + 1)` Hz,
+    /// where a ratio of zero counts as a half
+    #[inline]
+    pub const fn ratio(self, ratio: u16) -> Self {
+        Self(u16_with_value(0, 2, self.0, ratio))
+    }
+
+    /// A 7 bit counter repeats much sooner, which sounds more like a tone than a hiss
+    #[inline]
+    pub const fn short(self, short: bool) -> Self {
+        Self(u16_with_bit(3, self.0, short))
+    }
+
+    #[inline]
+    pub const fn shift(self, shift: u16) -> Self {
+        Self(u16_with_value(4, 7, self.0, shift))
+    }
+
+    #[inline]
+    pub const fn timed(self, timed: bool) -> Self {
+        Self(u16_with_bit(14, self.0, timed))
+    }
+
+    #[inline]
+    pub const fn restart(self) -> Self {
+        Self(u16_with_bit(15, self.0, true))
+    }
+}
+
+/// A triangle wave, which is a little softer than the square channels
+pub const TRIANGLE_WAVE: [u32; 4] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476];
+
+/// Turns the sound hardware on with every PSG channel at full volume in both
+/// speakers, and loads `TRIANGLE_WAVE` into the wave channel.
+pub fn init() {
+    // Nothing else can be written until the master switch is on
+    SOUNDCNT_X.write(SoundStatus::MASTER_ENABLE);
+    SOUNDCNT_L.write(
+        PsgControl::new()
+            .volume(7, 7)
+            .left(Channels::all())
+            .right(Channels::all()),
+    );
+    SOUNDCNT_H.write(MixControl::new().psg_volume(PsgVolume::Full));
+
+    load_wave(&TRIANGLE_WAVE);
+}
+
+/// Replaces the wave channel's samples, which are 4 bits each, high nibble first
+pub fn load_wave(samples: &[u32; 4]) {
+    // Play bank 1 while bank 0 is written, then switch over to it
+    SOUND3CNT_L.write(WaveControl::new().bank(1));
+    for (i, word) in samples.iter().enumerate() {
+        WAVE_RAM.index(i).write(*word);
+    }
+
+    SOUND3CNT_L.write(WaveControl::new().bank(0).enabled(true));
+}
+
+/// A note for one of the PSG channels, along with everything needed to start it
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Voice {
+    Square1(Sweep, Envelope, Tone),
+    Square2(Envelope, Tone),
+    Wave(WaveVolume, Tone),
+    Noise(Envelope, Noise),
+}
+
+impl Voice {
+    /// Starts the note, cutting off whatever the channel was playing
+    pub fn play(self) {
+        match self {
+            Self::Square1(sweep, envelope, tone) => {
+                SOUND1CNT_L.write(sweep);
+                SOUND1CNT_H.write(envelope);
+                SOUND1CNT_X.write(tone.restart());
+            }
+            Self::Square2(envelope, tone) => {
+                SOUND2CNT_L.write(envelope);
+                SOUND2CNT_H.write(tone.restart());
+            }
+            Self::Wave(volume, tone) => {
+                SOUND3CNT_H.write(volume);
+                SOUND3CNT_X.write(tone.restart());
+            }
+            Self::Noise(envelope, noise) => {
+                SOUND4CNT_L.write(envelope);
+                SOUND4CNT_H.write(noise.restart());
+            }
+        }
+    }
+}
+
+/// A note in a sound effect, played `delay` frames after the one before it.
+/// Notes with no delay start on the same frame, so they can be used for chords.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Note {
+    pub delay: u8,
+    pub voice: Voice,
+}
+
+impl Note {
+    pub const fn new(delay: u8, voice: Voice) -> Self {
+        Self { delay, voice }
+    }
+}
+
+pub type SoundEffect = &'static [Note];
+
+const QUEUE_LENGTH: usize = 8;
+
+/// How far through a sound effect playback has got
+#[derive(Clone, Copy)]
+struct Playing {
+    effect: SoundEffect,
+    /// The next note to play
+    note: usize,
+    wait: u8,
+}
+
+impl Playing {
+    const IDLE: Self = Self {
+        effect: &[],
+        note: 0,
+        wait: 0,
+    };
+
+    fn new(effect: SoundEffect) -> Self {
+        Self {
+            effect,
+            note: 0,
+            wait: effect.first().map_or(0, |note| note.delay),
+        }
+    }
+
+    /// Plays whichever notes are due this frame, returning false once
+    /// there are none left
+    fn update(&mut self) -> bool {
+        loop {
+            if self.wait > 0 {
+                self.wait -= 1;
+                return true;
+            }
+
+            let Some(note) = self.effect.get(self.note) else {
+                return false;
+            };
+
+            note.voice.play();
+            self.note += 1;
+
+            // The frame this note plays on is the first frame of the next one's delay
+            self.wait = self
+                .effect
+                .get(self.note)
+                .map_or(0, |note| note.delay.saturating_sub(1));
+        }
+    }
+}
+
+/*
+   Plays sound effects one after the other, a note at a time. `update`
+   needs to be called once a frame, since that's what the delays between
+   notes are measured in. The channels take care of how long each note
+   rings for, so there's nothing to do between notes.
+
+   Short effects, like the ones for button presses, can also be played
+   over the top of the queue, so that they don't cut off a longer
+   sequence that's part way through.
+*/
+pub struct SoundPlayer {
+    queue: [SoundEffect; QUEUE_LENGTH],
+    queued: usize,
+    /// The effect at the front of the queue
+    current: Playing,
+    /// The effect being played over the top of the queue
+    over: Playing,
+}
+
+impl SoundPlayer {
+    pub const fn new() -> Self {
+        Self {
+            queue: [&[]; QUEUE_LENGTH],
+            queued: 0,
+            current: Playing::IDLE,
+            over: Playing::IDLE,
+        }
+    }
+
+    /// Drops anything that's waiting to be played and starts `effect` on the next update
+    pub fn play(&mut self, effect: SoundEffect) {
+        self.stop();
+        self.queue(effect);
+    }
+
+    /// Starts `effect` on the next update without touching the queue. This
+    /// cuts off whatever was last played over it.
+    pub fn play_over(&mut self, effect: SoundEffect) {
+        self.over = Playing::new(effect);
+    }
+
+    /// Drops anything that's waiting to be played. Notes that have already
+    /// started will still ring out.
+    pub fn stop(&mut self) {
+        self.queued = 0;
+        self.over = Playing::IDLE;
+    }
+
+    /// Plays `effect` once everything before it has finished. The queue
+    /// holds up to 8 effects, and anything past that is dropped.
+    pub fn queue(&mut self, effect: SoundEffect) {
+        if self.queued == QUEUE_LENGTH {
+            return;
+        }
+
+        if self.queued == 0 {
+            self.current = Playing::new(effect);
+        }
+
+        self.queue[self.queued] = effect;
+        self.queued += 1;
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.queued > 0
+    }
+
+    pub fn update(&mut self) {
+        self.over.update();
+
+        while self.queued > 0 && !self.current.update() {
+            // Move on to the next effect in the queue
+            self.queue.copy_within(1.., 0);
+            self.queued -= 1;
+            if self.queued > 0 {
+                self.current = Playing::new(self.queue[0]);
+            }
+        }
+    }
+}
+
+impl Default for SoundPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
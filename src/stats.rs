@@ -0,0 +1,82 @@
+use wordboy::save::{Reader, Record, Slot, Writer};
+
+use wordboy_rules::board::MAX_GUESS_COUNT;
+
+const STATS_SLOT: Slot<Stats> = Slot::new(0);
+
+/// The most guesses a game can allow, plus one bucket for failed games
+pub const DISTRIBUTION_BUCKETS: usize = MAX_GUESS_COUNT + 1;
+pub const FAILED_BUCKET: usize = DISTRIBUTION_BUCKETS - 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    pub played: u16,
+    pub current_streak: u16,
+    pub max_streak: u16,
+    pub distribution: [u16; DISTRIBUTION_BUCKETS],
+}
+
+impl Stats {
+    /// Loads the stats from save memory, starting fresh if there aren't any
+    pub fn load() -> Self {
+        STATS_SLOT.load().unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        STATS_SLOT.store(self);
+    }
+
+    pub fn record_win(&mut self, guesses: usize) {
+        self.played = self.played.saturating_add(1);
+        self.current_streak = self.current_streak.saturating_add(1);
+        self.max_streak = self.max_streak.max(self.current_streak);
+
+        let bucket = guesses.clamp(1, FAILED_BUCKET) - 1;
+        self.distribution[bucket] = self.distribution[bucket].saturating_add(1);
+    }
+
+    pub fn record_loss(&mut self) {
+        self.played = self.played.saturating_add(1);
+        self.current_streak = 0;
+        self.distribution[FAILED_BUCKET] = self.distribution[FAILED_BUCKET].saturating_add(1);
+    }
+}
+
+impl Record for Stats {
+    const MAGIC: [u8; 4] = *b"STAT";
+    const VERSION: u16 = 2;
+    const SIZE: usize = 2 * (3 + DISTRIBUTION_BUCKETS);
+
+    fn encode(&self, writer: &mut Writer) {
+        writer.u16(self.played);
+        writer.u16(self.current_streak);
+        writer.u16(self.max_streak);
+        for count in self.distribution {
+            writer.u16(count);
+        }
+    }
+
+    fn decode(version: u16, reader: &mut Reader) -> Option<Self> {
+        // Games used to always allow six guesses, so older records
+        // have fewer buckets before the one for failed games
+        let solved_buckets = match version {
+            1 => 6,
+            Self::VERSION => MAX_GUESS_COUNT,
+            _ => return None,
+        };
+
+        let mut stats = Self {
+            played: reader.u16(),
+            current_streak: reader.u16(),
+            max_streak: reader.u16(),
+            distribution: [0; DISTRIBUTION_BUCKETS],
+        };
+
+        for count in stats.distribution[..solved_buckets].iter_mut() {
+            *count = reader.u16();
+        }
+
+        stats.distribution[FAILED_BUCKET] = reader.u16();
+        Some(stats)
+    }
+}
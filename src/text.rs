@@ -0,0 +1,161 @@
+use crate::video::{screen_block, TextEntry, SCREEN_BLOCK_WIDTH};
+
+/// The font starts at the space, and covers the rest of printable ASCII
+const FIRST_CHAR: u8 = b' ';
+const LAST_CHAR: u8 = b'~';
+/// Drawn in place of anything the font doesn't have
+const UNKNOWN_CHAR: u8 = b'?';
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// An area of a background, in tiles
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub const SCREEN: Self = Self::new(0, 0, 30, 20);
+
+    pub const fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/*
+   Draws text with an 8x8 font, one tile per character, onto the map in
+   a screen block. The font's tiles need to have been loaded into the
+   char block of whichever background shows that screen block, one per
+   character from the space onwards. Colors come from the palette bank
+   the text is drawn with, so the font only needs drawing once.
+*/
+pub struct TextLayer {
+    screen_block: usize,
+    font_tile: u16,
+}
+
+impl TextLayer {
+    /// `font_tile` is the index of the space in the background's char block
+    pub const fn new(screen_block: usize, font_tile: u16) -> Self {
+        Self {
+            screen_block,
+            font_tile,
+        }
+    }
+
+    pub fn clear(&self) {
+        let map = screen_block(self.screen_block);
+        for y in 0..SCREEN_BLOCK_WIDTH {
+            for x in 0..SCREEN_BLOCK_WIDTH {
+                map.index(x, y).write(TextEntry::new());
+            }
+        }
+    }
+
+    pub fn clear_rect(&self, rect: Rect) {
+        let map = screen_block(self.screen_block);
+        for y in rect.y..(rect.y + rect.height).min(SCREEN_BLOCK_WIDTH) {
+            for x in rect.x..(rect.x + rect.width).min(SCREEN_BLOCK_WIDTH) {
+                map.index(x, y).write(TextEntry::new());
+            }
+        }
+    }
+
+    /// Draws `text` inside `rect`, wrapping it at spaces and line breaks. Lines
+    /// that don't fit in the rect are dropped. Returns how many lines were drawn.
+    pub fn draw(&self, text: &str, rect: Rect, align: Align, palette: u16) -> usize {
+        let map = screen_block(self.screen_block);
+        let mut drawn = 0;
+        for (row, line) in wrap(text.as_bytes(), rect.width)
+            .take(rect.height)
+            .enumerate()
+        {
+            let indent = match align {
+                Align::Left => 0,
+                Align::Center => (rect.width - line.len()) / 2,
+                Align::Right => rect.width - line.len(),
+            };
+
+            let y = rect.y + row;
+            for (i, &char) in line.iter().enumerate() {
+                let x = rect.x + indent + i;
+                if x >= SCREEN_BLOCK_WIDTH || y >= SCREEN_BLOCK_WIDTH {
+                    continue;
+                }
+
+                let entry = TextEntry::new()
+                    .tile(self.font_tile + glyph(char) as u16)
+                    .palette(palette);
+                map.index(x, y).write(entry);
+            }
+
+            drawn += 1;
+        }
+
+        drawn
+    }
+}
+
+fn glyph(char: u8) -> u8 {
+    match char {
+        FIRST_CHAR..=LAST_CHAR => char - FIRST_CHAR,
+        _ => UNKNOWN_CHAR - FIRST_CHAR,
+    }
+}
+
+/// Splits text into lines no wider than `width`, breaking at the last space
+/// that fits. Words that are wider than a whole line are split wherever they
+/// run out of room.
+pub fn wrap(text: &[u8], width: usize) -> impl Iterator<Item = &[u8]> {
+    let mut rest = text;
+    core::iter::from_fn(move || {
+        if rest.is_empty() || width == 0 {
+            return None;
+        }
+
+        let paragraph_end = rest.iter().position(|&c| c == b'\n');
+        let paragraph = &rest[..paragraph_end.unwrap_or(rest.len())];
+
+        if paragraph.len() <= width {
+            rest = &rest[paragraph_end.map_or(rest.len(), |end| end + 1)..];
+            return Some(trim_end(paragraph));
+        }
+
+        // A space just past the edge still lets the whole of the line before it fit
+        let line = match paragraph[..=width].iter().rposition(|&c| c == b' ') {
+            Some(space) if space > 0 => {
+                rest = &rest[space + 1..];
+                &paragraph[..space]
+            }
+            _ => {
+                rest = &rest[width..];
+                &paragraph[..width]
+            }
+        };
+
+        // The spaces that a line was broken at shouldn't indent the next one
+        while let [b' ', tail @ ..] = rest {
+            rest = tail;
+        }
+
+        Some(trim_end(line))
+    })
+}
+
+fn trim_end(line: &[u8]) -> &[u8] {
+    let end = line.iter().rposition(|&c| c != b' ').map_or(0, |i| i + 1);
+    &line[..end]
+}
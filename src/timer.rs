@@ -0,0 +1,51 @@
+use bitfrob::{u16_with_bit, u16_with_value};
+
+/// The CPU clock, which the timers count at before the prescaler
+pub const CLOCK_HZ: u32 = 1 << 24;
+
+/// `TMxCNT_H`: how fast a timer counts, and whether it's running
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct TimerControl(pub u16);
+impl TimerControl {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn prescaler(self, prescaler: Prescaler) -> Self {
+        Self(u16_with_value(0, 1, self.0, prescaler as u16))
+    }
+
+    /// Counts once every time the previous timer overflows, ignoring the prescaler
+    #[inline]
+    pub const fn cascade(self, cascade: bool) -> Self {
+        Self(u16_with_bit(2, self.0, cascade))
+    }
+
+    #[inline]
+    pub const fn irq(self, irq: bool) -> Self {
+        Self(u16_with_bit(6, self.0, irq))
+    }
+
+    #[inline]
+    pub const fn enabled(self, enabled: bool) -> Self {
+        Self(u16_with_bit(7, self.0, enabled))
+    }
+}
+
+/// How many CPU cycles each tick of a timer takes
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Prescaler {
+    Cycles1 = 0,
+    Cycles64 = 1,
+    Cycles256 = 2,
+    Cycles1024 = 3,
+}
+
+/// The reload value for a timer with no prescaler to overflow `hz` times a second
+pub const fn reload_for_hz(hz: u32) -> u16 {
+    (65536 - CLOCK_HZ / hz) as u16
+}
@@ -1,6 +1,11 @@
-use bitfrob::u16_with_value;
+use bitfrob::{u16_with_bit, u16_with_value};
 
-use crate::mmio::REG_VCOUNT;
+use voladdress::{Safe, VolBlock, VolGrid2d};
+
+use crate::{
+    bios, dma, math,
+    mmio::{BG_TILE4, BLDCNT, BLDY, OBJ_ATTRS, SCREEN_BLOCKS},
+};
 
 pub const BITS_PER_BYTE: usize = 8;
 pub const PIXELS_PER_TILE: usize = 8 * 8;
@@ -10,6 +15,11 @@ pub const SIZE_OF_OBJ_TILE_MEM: usize = 32 * 1024;
 pub const TILE4_WORD_COUNT: usize = SIZE_OF_TILE4 / core::mem::size_of::<u32>();
 pub const TILE8_WORD_COUNT: usize = SIZE_OF_TILE8 / core::mem::size_of::<u32>();
 pub const OBJ_TILE_MEM_WORD_COUNT: usize = SIZE_OF_OBJ_TILE_MEM / core::mem::size_of::<u32>();
+pub const SIZE_OF_CHAR_BLOCK: usize = 16 * 1024;
+pub const CHAR_BLOCK_TILE4_COUNT: usize = SIZE_OF_CHAR_BLOCK / SIZE_OF_TILE4;
+pub const SIZE_OF_SCREEN_BLOCK: usize = 2 * 1024;
+/// Screen blocks are 32x32 tiles, no matter the size of the background
+pub const SCREEN_BLOCK_WIDTH: usize = 32;
 
 pub type Tile4 = [u32; TILE4_WORD_COUNT];
 pub type Tile8 = [u32; TILE8_WORD_COUNT];
@@ -53,6 +63,30 @@ bitflags::bitflags! {
     }
 }
 
+bitflags::bitflags! {
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct DisplayStatus: u16 {
+        const IN_VBLANK = 1 << 0;
+        const IN_HBLANK = 1 << 1;
+        const VCOUNT_MATCH = 1 << 2;
+        const VBLANK_IRQ = 1 << 3;
+        const HBLANK_IRQ = 1 << 4;
+        const VCOUNT_IRQ = 1 << 5;
+    }
+}
+
+/// How a sprite is drawn, other than as a regular sprite
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ObjMode {
+    Normal = 0,
+    /// Blended with whatever is behind it, as set up in `BLDCNT`
+    SemiTransparent = 1,
+    /// Not drawn, but used as the shape of the object window
+    Window = 2,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Default)]
 #[repr(transparent)]
 pub struct ObjAttr0(pub u16);
@@ -62,6 +96,41 @@ impl ObjAttr0 {
         Self(0)
     }
 
+    #[inline]
+    pub const fn affine(self, affine: bool) -> Self {
+        Self(u16_with_bit(8, self.0, affine))
+    }
+
+    /// Gives an affine sprite twice its size to be drawn in, so that it isn't
+    /// clipped when it's scaled up or rotated
+    #[inline]
+    pub const fn double_size(self, double_size: bool) -> Self {
+        Self(u16_with_bit(9, self.0, double_size))
+    }
+
+    /// Stops a regular sprite from being drawn. This is the same bit as
+    /// `double_size`, which is what it means for affine sprites.
+    #[inline]
+    pub const fn disabled(self, disabled: bool) -> Self {
+        Self(u16_with_bit(9, self.0, disabled))
+    }
+
+    #[inline]
+    pub const fn mode(self, mode: ObjMode) -> Self {
+        Self(u16_with_value(10, 11, self.0, mode as u16))
+    }
+
+    #[inline]
+    pub const fn mosaic(self, mosaic: bool) -> Self {
+        Self(u16_with_bit(12, self.0, mosaic))
+    }
+
+    /// Uses 256 color tiles, which take up two tile indices each, instead of 16 color ones
+    #[inline]
+    pub const fn bpp8(self, bpp8: bool) -> Self {
+        Self(u16_with_bit(13, self.0, bpp8))
+    }
+
     #[inline]
     pub const fn shape(self, shape: u16) -> Self {
         Self(u16_with_value(14, 15, self.0, shape))
@@ -82,6 +151,24 @@ impl ObjAttr1 {
         Self(0)
     }
 
+    /// Which of the affine parameter groups an affine sprite is transformed by
+    #[inline]
+    pub const fn affine_index(self, index: u16) -> Self {
+        Self(u16_with_value(9, 13, self.0, index))
+    }
+
+    /// Only for regular sprites, as this shares its bits with `affine_index`
+    #[inline]
+    pub const fn hflip(self, hflip: bool) -> Self {
+        Self(u16_with_bit(12, self.0, hflip))
+    }
+
+    /// Only for regular sprites, as this shares its bits with `affine_index`
+    #[inline]
+    pub const fn vflip(self, vflip: bool) -> Self {
+        Self(u16_with_bit(13, self.0, vflip))
+    }
+
     #[inline]
     pub const fn size(self, size: u16) -> Self {
         Self(u16_with_value(14, 15, self.0, size))
@@ -89,7 +176,7 @@ impl ObjAttr1 {
 
     #[inline]
     pub const fn x(self, x: i16) -> Self {
-        Self(u16_with_value(0, 9, self.0, x as u16))
+        Self(u16_with_value(0, 8, self.0, x as u16))
     }
 }
 
@@ -107,6 +194,12 @@ impl ObjAttr2 {
         Self(u16_with_value(0, 9, self.0, tile))
     }
 
+    /// From 0, drawn in front, to 3. Sprites are drawn in front of backgrounds of the same priority.
+    #[inline]
+    pub const fn priority(self, priority: u16) -> Self {
+        Self(u16_with_value(10, 11, self.0, priority))
+    }
+
     #[inline]
     pub const fn palette(self, bank: u16) -> Self {
         Self(u16_with_value(12, 15, self.0, bank))
@@ -114,9 +207,14 @@ impl ObjAttr2 {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
 pub struct ObjAttr(pub ObjAttr0, pub ObjAttr1, pub ObjAttr2);
 
 impl ObjAttr {
+    /// A sprite that isn't drawn at all. Unlike `ObjAttr::new()`, which is an
+    /// 8x8 sprite of tile 0 in the top left corner of the screen.
+    pub const HIDDEN: Self = Self::new().disabled(true);
+
     #[inline]
     pub const fn new() -> Self {
         Self(ObjAttr0::new(), ObjAttr1::new(), ObjAttr2::new())
@@ -136,10 +234,57 @@ impl ObjAttr {
         Self(self.0, self.1, self.2.tile(tile))
     }
 
+    #[inline]
     pub const fn palette(self, bank: u16) -> Self {
         Self(self.0, self.1, self.2.palette(bank))
     }
 
+    #[inline]
+    pub const fn priority(self, priority: u16) -> Self {
+        Self(self.0, self.1, self.2.priority(priority))
+    }
+
+    /// Transforms the sprite by one of the matrices set with `ShadowOam::set_affine`
+    #[inline]
+    pub const fn affine(self, index: u16) -> Self {
+        Self(self.0.affine(true), self.1.affine_index(index), self.2)
+    }
+
+    #[inline]
+    pub const fn double_size(self, double_size: bool) -> Self {
+        Self(self.0.double_size(double_size), self.1, self.2)
+    }
+
+    #[inline]
+    pub const fn disabled(self, disabled: bool) -> Self {
+        Self(self.0.disabled(disabled), self.1, self.2)
+    }
+
+    #[inline]
+    pub const fn mode(self, mode: ObjMode) -> Self {
+        Self(self.0.mode(mode), self.1, self.2)
+    }
+
+    #[inline]
+    pub const fn mosaic(self, mosaic: bool) -> Self {
+        Self(self.0.mosaic(mosaic), self.1, self.2)
+    }
+
+    #[inline]
+    pub const fn bpp8(self, bpp8: bool) -> Self {
+        Self(self.0.bpp8(bpp8), self.1, self.2)
+    }
+
+    #[inline]
+    pub const fn hflip(self, hflip: bool) -> Self {
+        Self(self.0, self.1.hflip(hflip), self.2)
+    }
+
+    #[inline]
+    pub const fn vflip(self, vflip: bool) -> Self {
+        Self(self.0, self.1.vflip(vflip), self.2)
+    }
+
     #[inline]
     pub const fn x(self, x: i16) -> Self {
         Self(self.0, self.1.x(x), self.2)
@@ -169,10 +314,430 @@ impl TileSize {
     pub const SIZE_32X64: Self = Self(0b10, 0b11);
 }
 
+/// `BGxCNT`: where a background's tiles and map are, and how it's drawn
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct BackgroundControl(pub u16);
+impl BackgroundControl {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// From 0, drawn in front, to 3
+    #[inline]
+    pub const fn priority(self, priority: u16) -> Self {
+        Self(u16_with_value(0, 1, self.0, priority))
+    }
+
+    /// Which of the four 16KB blocks of VRAM the tiles are in
+    #[inline]
+    pub const fn char_block(self, block: u16) -> Self {
+        Self(u16_with_value(2, 3, self.0, block))
+    }
+
+    #[inline]
+    pub const fn mosaic(self, mosaic: bool) -> Self {
+        Self(u16_with_bit(6, self.0, mosaic))
+    }
+
+    /// Uses 8bpp tiles with a single 256 color palette, rather than 4bpp tiles
+    #[inline]
+    pub const fn bpp8(self, bpp8: bool) -> Self {
+        Self(u16_with_bit(7, self.0, bpp8))
+    }
+
+    /// Which of the 32 2KB blocks of VRAM the map starts in
+    #[inline]
+    pub const fn screen_block(self, block: u16) -> Self {
+        Self(u16_with_value(8, 12, self.0, block))
+    }
+
+    #[inline]
+    pub const fn size(self, size: BackgroundSize) -> Self {
+        Self(u16_with_value(14, 15, self.0, size as u16))
+    }
+}
+
+/// The size of a tiled background, in tiles. Anything larger than 32x32
+/// spans more than one screen block.
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundSize {
+    Size32x32 = 0,
+    Size64x32 = 1,
+    Size32x64 = 2,
+    Size64x64 = 3,
+}
+
+/// An entry in a tiled background's map
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct TextEntry(pub u16);
+impl TextEntry {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Counted from the start of the background's char block
+    #[inline]
+    pub const fn tile(self, tile: u16) -> Self {
+        Self(u16_with_value(0, 9, self.0, tile))
+    }
+
+    #[inline]
+    pub const fn hflip(self, hflip: bool) -> Self {
+        Self(u16_with_bit(10, self.0, hflip))
+    }
+
+    #[inline]
+    pub const fn vflip(self, vflip: bool) -> Self {
+        Self(u16_with_bit(11, self.0, vflip))
+    }
+
+    #[inline]
+    pub const fn palette(self, bank: u16) -> Self {
+        Self(u16_with_value(12, 15, self.0, bank))
+    }
+}
+
+/// The 4bpp tiles of one of the four char blocks backgrounds read their tiles from
+pub const fn char_block(index: usize) -> VolBlock<Tile4, Safe, Safe, CHAR_BLOCK_TILE4_COUNT> {
+    assert!(index < 4);
+    unsafe { VolBlock::new(BG_TILE4.as_usize() + index * SIZE_OF_CHAR_BLOCK) }
+}
+
+/// One of the 32 screen blocks backgrounds read their maps from. These share
+/// VRAM with the char blocks, so the two have to be kept from overlapping.
+pub const fn screen_block(
+    index: usize,
+) -> VolGrid2d<TextEntry, Safe, Safe, SCREEN_BLOCK_WIDTH, SCREEN_BLOCK_WIDTH> {
+    match SCREEN_BLOCKS.get_frame(index) {
+        Some(block) => block,
+        None => panic!("screen block out of range"),
+    }
+}
+
+bitflags::bitflags! {
+    /// The layers picked out for blending, as the first or second target
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct BlendLayers: u16 {
+        const BG0 = 1 << 0;
+        const BG1 = 1 << 1;
+        const BG2 = 1 << 2;
+        const BG3 = 1 << 3;
+        const OBJ = 1 << 4;
+        const BACKDROP = 1 << 5;
+    }
+}
+
+impl BlendLayers {
+    pub const fn background(index: usize) -> Self {
+        Self::from_bits_truncate(1 << index)
+    }
+}
+
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BlendEffect {
+    None = 0,
+    /// Mixes the first target with the second target behind it, weighted by `BLDALPHA`
+    Alpha = 1,
+    /// Fades the first target towards white by `BLDY`
+    Brighten = 2,
+    /// Fades the first target towards black by `BLDY`
+    Darken = 3,
+}
+
+/// The most a blend coefficient can be, which is all the way
+pub const BLEND_MAX: u16 = 16;
+
+/// `BLDCNT`: which layers are blended, and how
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct BlendControl(pub u16);
+impl BlendControl {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn first_target(self, layers: BlendLayers) -> Self {
+        Self(u16_with_value(0, 5, self.0, layers.bits()))
+    }
+
+    #[inline]
+    pub const fn effect(self, effect: BlendEffect) -> Self {
+        Self(u16_with_value(6, 7, self.0, effect as u16))
+    }
+
+    /// Only used by alpha blending
+    #[inline]
+    pub const fn second_target(self, layers: BlendLayers) -> Self {
+        Self(u16_with_value(8, 13, self.0, layers.bits()))
+    }
+}
+
+/// `BLDALPHA`: how much of each target goes into an alpha blend, in sixteenths
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct BlendAlpha(pub u16);
+impl BlendAlpha {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn first(self, weight: u16) -> Self {
+        Self(u16_with_value(0, 4, self.0, weight))
+    }
+
+    #[inline]
+    pub const fn second(self, weight: u16) -> Self {
+        Self(u16_with_value(8, 12, self.0, weight))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FadeColor {
+    Black,
+    White,
+}
+
+/*
+   Fades layers towards black or white a step each frame, using the
+   brightness effect. The blending registers are only written when the
+   fade is ticked, so it should be ticked every frame, during VBlank,
+   until it's finished. Only one fade can be shown at a time, and
+   semi-transparent sprites aren't affected.
+*/
+pub struct Fade {
+    control: BlendControl,
+    from: u16,
+    to: u16,
+    ticks: u16,
+    tick: u16,
+}
+
+impl Fade {
+    /// Fades `layers` from `from` to `to` sixteenths of the way to `color`, over `ticks` frames
+    pub const fn new(
+        color: FadeColor,
+        layers: BlendLayers,
+        from: u16,
+        to: u16,
+        ticks: u16,
+    ) -> Self {
+        let effect = match color {
+            FadeColor::Black => BlendEffect::Darken,
+            FadeColor::White => BlendEffect::Brighten,
+        };
+
+        Self {
+            control: BlendControl::new().first_target(layers).effect(effect),
+            from,
+            to,
+            ticks,
+            tick: 0,
+        }
+    }
+
+    /// Fades the whole screen out to `color`
+    pub const fn fade_out(color: FadeColor, ticks: u16) -> Self {
+        Self::new(color, BlendLayers::all(), 0, BLEND_MAX, ticks)
+    }
+
+    /// Fades the whole screen in from `color`
+    pub const fn fade_in(color: FadeColor, ticks: u16) -> Self {
+        Self::new(color, BlendLayers::all(), BLEND_MAX, 0, ticks)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.tick >= self.ticks
+    }
+
+    /// How many sixteenths of the way to the fade's color it has got to so far
+    pub fn level(&self) -> u16 {
+        if self.ticks == 0 {
+            self.to
+        } else {
+            let delta = self.to as i32 - self.from as i32;
+            (self.from as i32 + delta * self.tick as i32 / self.ticks as i32) as u16
+        }
+    }
+
+    /// Moves the fade on by a frame, and shows it. Once a fade is all the way
+    /// back to nothing, the effect is turned off.
+    pub fn tick(&mut self) {
+        self.tick = (self.tick + 1).min(self.ticks);
+
+        let level = self.level();
+        if level == 0 {
+            BLDCNT.write(BlendControl::new());
+        } else {
+            BLDCNT.write(self.control);
+        }
+
+        BLDY.write(level);
+    }
+
+    /// Skips to the end of the fade, and shows it
+    pub fn finish(&mut self) {
+        self.tick = self.ticks;
+        self.tick();
+    }
+}
+
+/*
+   The transform of an affine sprite, in 8.8 fixed point. This maps
+   from the screen to the sprite's tiles rather than the other way
+   around, so it's the inverse of how the sprite ends up looking.
+*/
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct AffineMatrix {
+    pub pa: i16,
+    pub pb: i16,
+    pub pc: i16,
+    pub pd: i16,
+}
+
+impl AffineMatrix {
+    pub const IDENTITY: Self = Self::scale(math::ONE, math::ONE);
+
+    /// Stretches a sprite by `x` and `y`, where `math::ONE` is its normal size.
+    /// Negative factors mirror it.
+    pub const fn scale(x: i16, y: i16) -> Self {
+        Self {
+            pa: inverse(x),
+            pb: 0,
+            pc: 0,
+            pd: inverse(y),
+        }
+    }
+
+    /// Turns a sprite anticlockwise by `angle`, in 256ths of a turn
+    pub fn rotation(angle: u8) -> Self {
+        let (sin, cos) = (math::sin(angle), math::cos(angle));
+        Self {
+            pa: cos,
+            pb: -sin,
+            pc: sin,
+            pd: cos,
+        }
+    }
+}
+
+/// The reciprocal of an 8.8 number. Anything too close to zero to have one
+/// that fits is squashed as far as it can be instead.
+const fn inverse(value: i16) -> i16 {
+    const MIN: i32 = 3;
+
+    let value = value as i32;
+    let value = if value.abs() >= MIN {
+        value
+    } else if value < 0 {
+        -MIN
+    } else {
+        MIN
+    };
+
+    ((math::ONE as i32 * math::ONE as i32) / value) as i16
+}
+
+pub const OBJ_COUNT: usize = 128;
+/// Each affine matrix is spread over the unused fourth halfwords of four sprites
+pub const AFFINE_COUNT: usize = OBJ_COUNT / 4;
+
+/*
+   A copy of OAM that lives in RAM. Sprites are pushed into it while
+   rendering, which can happen at any point in the frame, and then the
+   whole thing is copied over to OAM in one go during VBlank so the
+   display never shows a half drawn frame.
+*/
+#[repr(C, align(4))]
+pub struct ShadowOam {
+    entries: [OamEntry; OBJ_COUNT],
+    len: usize,
+}
+
+/// The layout of a sprite in OAM. Every fourth halfword belongs to the
+/// affine parameters, which are interleaved with the sprite attributes.
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct OamEntry {
+    attr: ObjAttr,
+    affine_param: u16,
+}
+
+impl ShadowOam {
+    pub const fn new() -> Self {
+        Self {
+            entries: [OamEntry {
+                attr: ObjAttr::HIDDEN,
+                affine_param: 0,
+            }; OBJ_COUNT],
+            len: 0,
+        }
+    }
+
+    /// Starts a new frame. Nothing pushed before this will be committed.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Adds a sprite to the frame. Sprites pushed first are drawn on top,
+    /// and anything past the 128 sprites the hardware supports is dropped.
+    pub fn push(&mut self, attr: ObjAttr) {
+        if let Some(entry) = self.entries.get_mut(self.len) {
+            entry.attr = attr;
+            self.len += 1;
+        }
+    }
+
+    /// Sets one of the matrices that sprites using `ObjAttr::affine` are transformed by.
+    /// Unlike sprites, these stay put from frame to frame.
+    pub fn set_affine(&mut self, index: usize, matrix: AffineMatrix) {
+        assert!(index < AFFINE_COUNT);
+
+        let params = [matrix.pa, matrix.pb, matrix.pc, matrix.pd];
+        for (entry, param) in self.entries[index * 4..].iter_mut().zip(params) {
+            entry.affine_param = param as u16;
+        }
+    }
+
+    /// Copies the frame to OAM, hiding any sprites that weren't pushed. This
+    /// should be called during VBlank, ie. right after `wait_vblank`.
+    pub fn commit(&mut self) {
+        for entry in &mut self.entries[self.len..] {
+            // Sprites that aren't in use are disabled, rather than left
+            // as whatever they were last frame
+            entry.attr = ObjAttr::HIDDEN;
+        }
+
+        const WORD_COUNT: usize = core::mem::size_of::<[OamEntry; OBJ_COUNT]>() / 4;
+        unsafe {
+            dma::copy_u32(
+                self.entries.as_ptr().cast(),
+                OBJ_ATTRS.index(0).as_mut_ptr().cast(),
+                WORD_COUNT as u16,
+            );
+        }
+    }
+}
+
+impl Default for ShadowOam {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sleeps until the start of the next VBlank. This relies on the VBlank
+/// interrupt, so `interrupt::init` needs to have been called and
+/// `Interrupt::VBLANK` enabled first.
 pub fn wait_vblank() {
-    // Poor mans vsync. What should actually be done is
-    // enable the VBlank interrupt, put the CPU to sleep,
-    // and wake up when the interrupt is triggered.
-    while REG_VCOUNT.read() >= 160 {}
-    while REG_VCOUNT.read() < 160 {}
+    bios::vblank_intr_wait();
 }